@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+use crate::Game;
+
+/// Mixed into the date so daily games don't line up with small seeds handed to [`Game::from_seed`].
+const DAILY_SALT: u64 = 0x776f_7264_735f_6861;
+
+/// A day on the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Today's date in UTC, so everyone in the world gets the same puzzle at the same time.
+    pub fn today() -> Self {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|v| v.as_secs())
+            .unwrap_or_default();
+
+        Self::from_days_since_epoch((secs / 86_400) as i64)
+    }
+
+    /// Converts the number of days since 1970-01-01 into a date.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // this is Howard Hinnant's `civil_from_days`
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+
+        Self { year, month, day }
+    }
+
+    /// The number of days since 1970-01-01.
+    pub fn days_since_epoch(self) -> i64 {
+        // and this is `days_from_civil`
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let month = i64::from(self.month);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146_097 + doe - 719_468
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parses a `YYYY-MM-DD` date.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad_date = || format!("'{}' is not a YYYY-MM-DD date", s);
+
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(bad_date);
+        let year = next()?.parse().map_err(|_| bad_date())?;
+        let month = next()?.parse().map_err(|_| bad_date())?;
+        let day = next()?.parse().map_err(|_| bad_date())?;
+
+        let date = Date::new(year, month, day);
+
+        // round tripping catches the 31st of February and friends
+        if Date::from_days_since_epoch(date.days_since_epoch()) == date {
+            Ok(date)
+        } else {
+            Err(bad_date())
+        }
+    }
+}
+
+impl Game {
+    /// Creates the daily game for the date. Everyone playing on the same date gets the same game.
+    pub fn daily(date: Date) -> Self {
+        let mut game = Self::unique_from_seed(date.days_since_epoch() as u64 ^ DAILY_SALT);
+        game.date = Some(date);
+
+        game
+    }
+
+    /// Describes how the game went without giving any of the words away, so it can be shared around.
+    ///
+    /// Every played round gets a line, with a square for each attempt and a bulb for each hint. Only daily games are
+    /// the same for everyone, so other games return `None`.
+    pub fn share_string(&self) -> Option<String> {
+        let date = self.date?;
+        let mut output = format!("words are hard {} {}/{}", date, self.current_round(), self.len());
+
+        for (round, stats) in self.stats().iter().enumerate() {
+            let solved = round < self.current_round() && stats.failed == false;
+            if solved == false && stats.attempts == 0 {
+                break;
+            }
+
+            output.push_str(&format!("\n{} ", round + 1));
+            for _ in 1..stats.attempts {
+                output.push('⬛');
+            }
+            output.push(if solved { '🟩' } else { '🟥' });
+
+            if stats.hints > 0 {
+                output.push_str(&format!(" 💡{}", stats.hints));
+            }
+        }
+
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        assert_eq!(Date::from_days_since_epoch(0), Date::new(1970, 1, 1));
+        assert_eq!(Date::new(2000, 3, 1).days_since_epoch(), 11_017);
+        assert_eq!("2026-10-18".parse(), Ok(Date::new(2026, 10, 18)));
+        assert_eq!(Date::new(2024, 2, 9).to_string(), "2024-02-09");
+
+        assert!("2026-02-30".parse::<Date>().is_err());
+        assert!("yesterday".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn share_string() {
+        let date = Date::new(2026, 10, 18);
+        let mut game = Game::daily(date);
+
+        game.record_attempt();
+        game.advance_game();
+        game.record_attempt();
+        game.record_attempt();
+        game.record_attempt();
+        game.advance_game();
+        game.record_attempt();
+
+        assert_eq!(
+            game.share_string().unwrap(),
+            "words are hard 2026-10-18 2/8\n1 🟩\n2 ⬛⬛🟩\n3 🟥"
+        );
+        assert_eq!(Game::from_seed(1).share_string(), None);
+    }
+}
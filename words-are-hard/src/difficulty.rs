@@ -0,0 +1,193 @@
+use std::ops::Range;
+
+use crate::{apply_all, solve_in, ChallengeInstruction, Rule, WordList};
+
+/// Every rule is a little more to keep in your head.
+const RULE_WEIGHT: f32 = 1.0;
+/// Letters that are gone can only be guessed at.
+const DESTROYED_WEIGHT: f32 = 1.5;
+/// Letters that moved have to be put back.
+const MOVED_WEIGHT: f32 = 0.5;
+/// Letters that were shifted have to be shifted back.
+const SHIFTED_WEIGHT: f32 = 0.5;
+/// Applied to the log2 of the number of words which make the same hard word.
+const AMBIGUITY_WEIGHT: f32 = 2.0;
+
+/// Scores how hard it is to get from the hard word back to the secret, using the embedded English words. See
+/// [`difficulty_in`].
+pub fn difficulty(rules: &[Rule], secret: &str) -> f32 {
+    difficulty_in(rules, secret, &WordList::embedded())
+}
+
+/// Scores how hard it is to get from the hard word back to the secret. Higher is harder.
+///
+/// This looks at how many letters get destroyed (by `Remove`, or by a `Convert` onto a letter the word already
+/// has), how many letters a `Switch`, `Reverse` or `Swap` moves around, how many a `Shift` changes, how many rules
+/// there are, and how many other words in the dictionary the rules turn into the same hard word.
+pub fn difficulty_in(rules: &[Rule], secret: &str, dictionary: &WordList) -> f32 {
+    let hard_word = apply_all(rules, secret);
+    // the secret itself might not be in the dictionary
+    let others = solve_in(rules, &hard_word, dictionary)
+        .into_iter()
+        .filter(|v| v != secret)
+        .count();
+
+    score(rules, secret, others)
+}
+
+/// Scores rounds like [`difficulty_in`], where each round is a secret and the range of `rules` it executes. Rounds
+/// which start on the same rule share the work of running every word in the dictionary through them.
+pub(crate) fn round_difficulties(rules: &[Rule], rounds: &[(Range<usize>, &str)], dictionary: &WordList) -> Vec<f32> {
+    let hard_words: Vec<String> = rounds
+        .iter()
+        .map(|(chain, secret)| apply_all(&rules[chain.clone()], secret))
+        .collect();
+    let mut others = vec![0; rounds.len()];
+
+    let mut starts: Vec<usize> = rounds.iter().map(|(chain, _)| chain.start).collect();
+    starts.sort_unstable();
+    starts.dedup();
+
+    for start in starts {
+        let group: Vec<usize> = (0..rounds.len()).filter(|i| rounds[*i].0.start == start).collect();
+        let end = group.iter().map(|i| rounds[*i].0.end).max().unwrap_or(start);
+
+        for word in dictionary {
+            // what the word turns into after each rule, starting with none of them
+            let mut outputs = vec![word.clone()];
+            for rule in &rules[start..end] {
+                outputs.push(rule.apply(outputs.last().unwrap()));
+            }
+
+            for i in group.iter().copied() {
+                let (chain, secret) = &rounds[i];
+                if outputs[chain.end - start] == hard_words[i] && word != secret {
+                    others[i] += 1;
+                }
+            }
+        }
+    }
+
+    rounds
+        .iter()
+        .zip(others)
+        .map(|((chain, secret), others)| score(&rules[chain.clone()], secret, others))
+        .collect()
+}
+
+/// Scores the rules on the secret, given how many other words they turn into the same hard word.
+fn score(rules: &[Rule], secret: &str, others: usize) -> f32 {
+    let mut destroyed = 0;
+    let mut moved = 0;
+    let mut shifted = 0;
+
+    let mut word = secret.to_string();
+    for rule in rules {
+        let output = rule.apply(&word);
+
+        match rule.innermost() {
+            Rule::Convert(cnv) => {
+                let chars: Vec<char> = word.chars().collect();
+                let selected = rule.selection(&chars);
+                let converted: Vec<char> = chars
+                    .into_iter()
+                    .zip(selected)
+                    .filter(|(chr, selected)| *selected && *chr != cnv.destination)
+                    .map(|(chr, _)| chr)
+                    .collect();
+                let mut distinct = converted.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+
+                // converting is only destructive once letters merge together
+                if word.contains(cnv.destination) || distinct.len() > 1 {
+                    destroyed += converted.len();
+                }
+            }
+            Rule::Duplicate(dup) => {
+                if dup.count == 0 {
+                    destroyed += word.chars().count() - output.chars().count();
+                }
+            }
+            Rule::Remove(_) => destroyed += word.chars().count() - output.chars().count(),
+            // inserting leaves every letter where it can be seen
+            Rule::Insert(_) => {}
+            Rule::Switch(_) | Rule::Reverse(_) | Rule::Swap(_) => {
+                moved += word.chars().zip(output.chars()).filter(|(a, b)| a != b).count()
+            }
+            Rule::Shift(_) => shifted += word.chars().zip(output.chars()).filter(|(a, b)| a != b).count(),
+            Rule::When(_) => unreachable!("`innermost` never gives a `When`"),
+        }
+
+        word = output;
+    }
+
+    let preimages = others + 1;
+
+    RULE_WEIGHT * rules.len() as f32
+        + DESTROYED_WEIGHT * destroyed as f32
+        + MOVED_WEIGHT * moved as f32
+        + SHIFTED_WEIGHT * shifted as f32
+        + AMBIGUITY_WEIGHT * (preimages as f32).log2()
+}
+
+/// Scores a challenge pack by its hardest round. Packs are in English, so this uses the embedded words.
+pub fn pack_difficulty(instructions: &[ChallengeInstruction]) -> f32 {
+    let rules: Vec<_> = instructions.iter().map(|v| v.rule.clone()).collect();
+    let rounds: Vec<_> = instructions
+        .iter()
+        .enumerate()
+        .map(|(i, instruction)| (0..i + 1, instruction.word.as_str()))
+        .collect();
+
+    round_difficulties(&rules, &rounds, &WordList::embedded())
+        .into_iter()
+        .fold(0.0, f32::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LetterClass, Target};
+
+    #[test]
+    fn harder_rules_score_higher() {
+        let convert = difficulty(&[Rule::convert('r', 'e')], "arbitrary");
+        let merge = difficulty(&[Rule::convert('r', 'a')], "arbitrary");
+        let remove = difficulty(&[Rule::remove('r')], "arbitrary");
+        let longer = difficulty(&[Rule::convert('r', 'e'), Rule::switch('a', 't')], "arbitrary");
+
+        assert!(convert < merge, "{} < {}", convert, merge);
+        assert!(convert < remove, "{} < {}", convert, remove);
+        assert!(convert < longer, "{} < {}", convert, longer);
+        assert_eq!(difficulty(&[Rule::convert('z', 'e')], "arbitrary"), 1.0);
+    }
+
+    #[test]
+    fn counts_other_words() {
+        let dictionary = WordList::parse("cat\nbat\nhat\ndog\n");
+        let rules = [Rule::convert(Target::Any, 'x')];
+
+        // every three letter word becomes xxx
+        assert_eq!(difficulty_in(&rules, "cat", &dictionary), 1.0 + 1.5 * 3.0 + 2.0 * 2.0);
+        assert_eq!(
+            difficulty_in(&[Rule::shift(LetterClass::Vowels, 1)], "cat", &dictionary),
+            1.5
+        );
+    }
+
+    #[test]
+    fn packs_score_by_hardest_round() {
+        let easy = [ChallengeInstruction::new(Rule::convert('r', 'e'), "arbitrary")];
+        let hard = [
+            ChallengeInstruction::new(Rule::convert('r', 'e'), "arbitrary"),
+            ChallengeInstruction::new(Rule::remove('h'), "horoscope"),
+        ];
+
+        assert!(pack_difficulty(&easy) < pack_difficulty(&hard));
+        assert_eq!(
+            pack_difficulty(&hard),
+            difficulty(&[Rule::convert('r', 'e'), Rule::remove('h')], "horoscope")
+        );
+    }
+}
@@ -1,27 +1,127 @@
-use crate::{gen, ChallengeInstruction, Rule, TargetDestination};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::{ops::Range, time::Duration};
+
+use crate::{
+    apply_all,
+    difficulty::round_difficulties,
+    gen,
+    save::{Generator, SaveFile, SaveVersion},
+    solve_in, ChallengeInstruction, Clock, Date, Endless, GameMode, GameState, GeneratorConfig, Hint, Language,
+    LoadError, Overflow, Rule, ScoreFormula, Scorecard, SystemClock, TargetDestination, SAVE_VERSION,
+};
 
 /// The main struct of the game.
 pub struct Game {
     rules: Vec<Rule>,
     words: Vec<WordData>,
+    /// The rules each round executes, as a range of `rules`.
+    chains: Vec<Range<usize>>,
     len: usize,
+    language: Language,
+    mode: GameMode,
+    /// What endless games make their next round out of.
+    generator: Option<Generator>,
+    /// The furthest the player's gotten before this game.
+    best_depth: usize,
+    /// The day a daily game is for.
+    pub(crate) date: Option<Date>,
 
     current_round: usize,
+    stats: Vec<RoundStats>,
+    clock: Box<dyn Clock>,
+    /// When the current round started by the clock, for timing it.
+    round_started: Duration,
 }
 
 impl Game {
     pub fn new() -> Self {
-        let mut rng = rand::thread_rng();
+        Self::with_config(&GeneratorConfig::default())
+    }
 
-        let mut rules = vec![Rule::Convert(TargetDestination::default()); 8];
-        let mut words = vec![WordData::default(); 8];
+    /// Creates a new random game. The same seed always makes the same game, so seeds can be shared around.
+    pub fn from_seed(seed: u64) -> Self {
+        Self::from_seed_with_config(seed, &GeneratorConfig::default())
+    }
+
+    /// Creates a new random game where every rule changes the word and every round has exactly one answer in
+    /// the language's words. Word lists too small for that get rounds which might not, which
+    /// [`Game::ambiguous_rounds`] lists.
+    pub fn new_unique() -> Self {
+        Self::with_config(&GeneratorConfig::medium())
+    }
+
+    /// Like [`Game::from_seed`], but with the guarantees of [`Game::new_unique`].
+    pub fn unique_from_seed(seed: u64) -> Self {
+        Self::from_seed_with_config(seed, &GeneratorConfig::medium())
+    }
+
+    /// Creates a new random game following the config.
+    pub fn with_config(config: &GeneratorConfig) -> Self {
+        Self::from_rng_with_config(&mut rand::thread_rng(), config)
+    }
+
+    /// Creates a new random game following the config. The same seed and config always make the same game.
+    pub fn from_seed_with_config(seed: u64, config: &GeneratorConfig) -> Self {
+        Self::from_rng_with_config(&mut ChaCha8Rng::seed_from_u64(seed), config)
+    }
+
+    /// Creates a new random game out of the given rng, following the config.
+    pub fn from_rng_with_config<R: Rng + ?Sized>(rng: &mut R, config: &GeneratorConfig) -> Self {
+        Self::from_rng_with_language(rng, config, &Language::english())
+    }
+
+    /// Creates a new random game in the language, following the config.
+    pub fn with_language(config: &GeneratorConfig, language: &Language) -> Self {
+        Self::from_rng_with_language(&mut rand::thread_rng(), config, language)
+    }
+
+    /// Creates a new random game in the language, following the config. The same seed, config and language always
+    /// make the same game.
+    pub fn from_seed_with_language(seed: u64, config: &GeneratorConfig, language: &Language) -> Self {
+        Self::from_rng_with_language(&mut ChaCha8Rng::seed_from_u64(seed), config, language)
+    }
 
-        for i in 0..8 {
-            // first, generate our new word!
-            let (secret, selection_range) = gen::generate_word(&mut rng, &rules[0..i]);
+    /// Creates a new random game in the language out of the given rng, following the config.
+    pub fn from_rng_with_language<R: Rng + ?Sized>(rng: &mut R, config: &GeneratorConfig, language: &Language) -> Self {
+        let band = match &config.difficulty {
+            Some(band) => band,
+            None => return Self::generate(rng, config, language),
+        };
 
-            // and get a new rule!
-            rules[i] = gen::generate_rule(i, &mut rng, selection_range);
+        let distance = |game: &Game| {
+            let difficulty = game.difficulty();
+            (band.start() - difficulty).max(difficulty - band.end()).max(0.0)
+        };
+
+        let mut best = Self::generate(rng, config, language);
+        for _ in 1..gen::DIFFICULTY_ATTEMPTS {
+            if distance(&best) == 0.0 {
+                break;
+            }
+
+            let game = Self::generate(rng, config, language);
+            if distance(&game) < distance(&best) {
+                best = game;
+            }
+        }
+
+        best
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, config: &GeneratorConfig, language: &Language) -> Self {
+        if config.unique {
+            return Self::unique_from_rng_with_config(rng, config, language);
+        }
+
+        let mut rules = vec![Rule::Convert(TargetDestination::default()); config.rounds];
+        let mut words = vec![WordData::default(); config.rounds];
+
+        for i in 0..config.rounds {
+            // first, generate our new word and rule!
+            let (secret, rule) = gen::generate_round(config, language, rng, &rules[0..i]);
+            rules[i] = rule;
 
             // okay GOOD LUCK PLAYER!
             let mut hard_word = secret.to_string();
@@ -35,14 +135,238 @@ impl Game {
             };
         }
 
-        Game {
+        Self::from_rounds(rules, words, language.clone())
+    }
+
+    fn unique_from_rng_with_config<R: Rng + ?Sized>(
+        rng: &mut R,
+        config: &GeneratorConfig,
+        language: &Language,
+    ) -> Self {
+        let mut restarts = 0;
+
+        'game: loop {
+            let mut rules = Vec::with_capacity(config.rounds);
+            let mut words = Vec::with_capacity(config.rounds);
+
+            for _ in 0..config.rounds {
+                let (secret, rule) = match gen::generate_unique_round(config, language, rng, &rules) {
+                    Some(round) => round,
+                    // these rules have painted us into a corner, so we start over
+                    None if restarts < gen::UNIQUE_RESTARTS => {
+                        restarts += 1;
+                        continue 'game;
+                    }
+                    // the words can't make a game of unique rounds however we start, see `ambiguous_rounds`
+                    None => gen::generate_changing_round(config, language, rng, &rules),
+                };
+                rules.push(rule);
+
+                words.push(WordData {
+                    secret: secret.to_string(),
+                    hard_word: apply_all(&rules, secret),
+                });
+            }
+
+            return Self::from_rounds(rules, words, language.clone());
+        }
+    }
+
+    /// Creates an endless game, which makes each round as the one before it is solved.
+    pub fn endless(config: &GeneratorConfig, language: &Language, endless: Endless) -> Self {
+        Self::endless_from_seed(rand::random(), config, language, endless)
+    }
+
+    /// Like [`Game::endless`], but the same seed, config and language always make the same rounds.
+    pub fn endless_from_seed(seed: u64, config: &GeneratorConfig, language: &Language, endless: Endless) -> Self {
+        let mut game = Self::from_rounds(vec![], vec![], language.clone()).with_mode(GameMode::Endless(endless));
+        game.generator = Some(Generator {
+            config: config.clone(),
+            seed,
+        });
+        game.generate_round();
+
+        game
+    }
+
+    /// Adds a round to an endless game, with a new rule on top of the last round's unless it's at the cap.
+    fn generate_round(&mut self) {
+        let (generator, endless) = match (&self.generator, self.mode) {
+            (Some(generator), GameMode::Endless(endless)) => (generator, endless),
+            _ => return,
+        };
+        let config = &generator.config;
+        let mut rng = ChaCha8Rng::seed_from_u64(generator.seed);
+        rng.set_stream(self.len as u64);
+
+        let mut chain = self.chains.last().cloned().unwrap_or_default();
+        let full = chain.len() >= endless.max_rules.max(1);
+
+        let secret = if full && endless.overflow == Overflow::Stack {
+            gen::generate_secret(config, &self.language, &mut rng, &self.rules[chain.clone()])
+        } else {
+            if full {
+                chain.start += 1;
+            }
+            let previous = &self.rules[chain.clone()];
+
+            let (secret, rule) = if config.unique == false {
+                gen::generate_round(config, &self.language, &mut rng, previous)
+            } else {
+                match gen::generate_unique_round(config, &self.language, &mut rng, previous) {
+                    Some(round) => round,
+                    // there's no starting over in an endless game, so settle for a round which might not be unique
+                    None => gen::generate_changing_round(config, &self.language, &mut rng, previous),
+                }
+            };
+
+            self.rules.push(rule);
+            chain.end = self.rules.len();
+
+            secret
+        };
+
+        self.words.push(WordData {
+            secret: secret.to_string(),
+            hard_word: apply_all(&self.rules[chain.clone()], secret),
+        });
+        self.chains.push(chain);
+        self.stats.push(RoundStats::default());
+        self.len += 1;
+    }
+
+    fn from_rounds(rules: Vec<Rule>, words: Vec<WordData>, language: Language) -> Self {
+        let len = words.len();
+
+        Self {
             rules,
             words,
-            len: 8,
+            chains: (0..len).map(|i| 0..i + 1).collect(),
+            len,
+            language,
+            mode: GameMode::default(),
+            generator: None,
+            best_depth: 0,
+            date: None,
             current_round: 0,
+            stats: vec![RoundStats::default(); len],
+            clock: Box::new(SystemClock::new()),
+            round_started: Duration::ZERO,
         }
     }
 
+    /// The [`difficulty`](crate::difficulty_in) of the hardest round, against the language's words.
+    pub fn difficulty(&self) -> f32 {
+        let rounds: Vec<_> = self
+            .chains
+            .iter()
+            .cloned()
+            .zip(self.words.iter().map(|v| v.secret.as_str()))
+            .collect();
+
+        round_difficulties(&self.rules, &rounds, &self.language.words)
+            .into_iter()
+            .fold(0.0, f32::max)
+    }
+
+    /// The rounds which more than one of the language's words would solve.
+    pub fn ambiguous_rounds(&self) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(_, round_data)| {
+                let word_data = round_data.word_data;
+                solve_in(round_data.rules, &word_data.hard_word, &self.language.words) != [word_data.secret.as_str()]
+            })
+            .map(|(round, _)| round)
+            .collect()
+    }
+
+    /// Plays the game in the mode, rather than the classic one.
+    pub fn with_mode(mut self, mode: GameMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    /// Times rounds with the clock, rather than the real time. The current round starts over.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.round_started = clock.now();
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// How long the current round's been going.
+    fn round_elapsed(&self) -> Duration {
+        self.clock.now().saturating_sub(self.round_started)
+    }
+
+    /// How long is left to solve the current round, or `None` if the mode doesn't time rounds.
+    pub fn time_left(&self) -> Option<Duration> {
+        match self.mode {
+            GameMode::TimeAttack(limit) if self.state() == GameState::InProgress => {
+                // a loaded game has already used up some of the round's time
+                let elapsed = self.stats[self.current_round].elapsed + self.round_elapsed();
+                Some(limit.saturating_sub(elapsed))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks the current round's timer, failing the round and moving on if it's run out. Returns if it did.
+    pub fn tick(&mut self) -> bool {
+        if self.time_left() != Some(Duration::ZERO) {
+            return false;
+        }
+
+        self.stats[self.current_round].failed = true;
+        self.advance_game();
+
+        true
+    }
+
+    /// Whether the game's been won or lost yet.
+    pub fn state(&self) -> GameState {
+        if self.lives_left() == Some(0) {
+            GameState::Lost
+        } else if self.current_round < self.len {
+            GameState::InProgress
+        } else if self.stats.iter().any(|v| v.failed) {
+            GameState::Lost
+        } else {
+            GameState::Won
+        }
+    }
+
+    /// How many more wrong guesses the player can make, or `None` if the mode doesn't limit them.
+    pub fn lives_left(&self) -> Option<usize> {
+        match self.mode {
+            GameMode::Classic | GameMode::TimeAttack(_) | GameMode::Endless(_) => None,
+            GameMode::Lives(lives) => Some(lives.saturating_sub((0..self.len).map(|v| self.wrong_guesses(v)).sum())),
+            GameMode::LivesPerRound(lives) => Some(lives.saturating_sub(self.wrong_guesses(self.current_round))),
+        }
+    }
+
+    /// How many guesses at the round didn't solve it.
+    fn wrong_guesses(&self, round: usize) -> usize {
+        self.stats.get(round).map_or(0, |stats| {
+            let solved = round < self.current_round && stats.failed == false;
+            stats.attempts.saturating_sub(solved as usize)
+        })
+    }
+
+    /// The day the game is for, if it's a [daily](Game::daily) one.
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+
+    /// The language the game's secrets are in.
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
     /// Get a reference to the game's len.
     pub fn len(&self) -> usize {
         self.len
@@ -53,15 +377,199 @@ impl Game {
     }
 
     pub fn advance_game(&mut self) {
+        if self.current_round + 1 == self.len {
+            self.generate_round();
+        }
+
+        let elapsed = self.round_elapsed();
+        if let Some(stats) = self.stats.get_mut(self.current_round) {
+            stats.elapsed += elapsed;
+        }
+        self.round_started = self.clock.now();
+
         self.current_round += 1;
     }
 
+    /// Remembers how far the player's gotten in other games, for [`Game::best_depth`].
+    pub fn with_best_depth(mut self, best_depth: usize) -> Self {
+        self.best_depth = best_depth;
+        self
+    }
+
+    /// The most rounds the player's gotten through, in this game or the ones before it.
+    pub fn best_depth(&self) -> usize {
+        self.best_depth.max(self.current_round)
+    }
+
+    /// The index of the round being played. Equal to `len` once the game is over.
+    pub fn current_round(&self) -> usize {
+        self.current_round
+    }
+
+    /// Counts a guess against the current round.
+    pub fn record_attempt(&mut self) {
+        if let Some(stats) = self.stats.get_mut(self.current_round) {
+            stats.attempts += 1;
+        }
+    }
+
+    /// Every word which solves the current round: the secret, and any other word in the word list which the rules
+    /// turn into the same hard word.
+    pub fn answers(&self) -> Vec<String> {
+        let round_data = match self.round_data() {
+            Some(round_data) => round_data,
+            None => return vec![],
+        };
+        let secret = &round_data.word_data.secret;
+
+        let mut answers = vec![secret.clone()];
+        answers.extend(
+            solve_in(round_data.rules, &round_data.word_data.hard_word, &self.language.words)
+                .into_iter()
+                .filter(|word| word != secret),
+        );
+
+        answers
+    }
+
+    /// Checks a guess at the current round's secret, without counting it. See [`Game::submit_guess`].
+    pub fn check_guess(&self, guess: &str) -> GuessOutcome {
+        let round_data = match self.round_data() {
+            Some(round_data) => round_data,
+            None => return GuessOutcome::NotAWord,
+        };
+        let guess = guess.trim().to_lowercase();
+
+        if guess == round_data.word_data.secret {
+            return GuessOutcome::Correct;
+        }
+        if self.language.words.contains(&guess) == false {
+            return GuessOutcome::NotAWord;
+        }
+
+        let hard_word = apply_all(round_data.rules, &guess);
+        if hard_word == round_data.word_data.hard_word {
+            GuessOutcome::AlternativeSolution
+        } else {
+            GuessOutcome::ValidButWrong(hard_word)
+        }
+    }
+
+    /// Guesses the current round's secret. Real words count as an attempt, and if the guess is accepted the game moves
+    /// on to the next round. Once the game's been won or lost, nothing counts, and if the round's run out of time the
+    /// guess is too late.
+    pub fn submit_guess(&mut self, guess: &str) -> GuessOutcome {
+        if self.tick() {
+            return GuessOutcome::TimeUp;
+        }
+
+        let outcome = self.check_guess(guess);
+        if self.state() != GameState::InProgress {
+            return outcome;
+        }
+
+        if outcome != GuessOutcome::NotAWord {
+            self.record_attempt();
+        }
+        if outcome.is_accepted() {
+            self.advance_game();
+        }
+
+        outcome
+    }
+
+    /// Gives a hint for the current round, from the length of the secret at level 0 to undoing the last rule at
+    /// level 3, which rounds with only one rule don't get. The round's stats remember the strongest hint taken.
+    ///
+    /// Rounds which have run out of time don't get any, and are failed just like [`Game::tick`] does.
+    pub fn hint(&mut self, level: usize) -> Option<Hint> {
+        if self.tick() {
+            return None;
+        }
+
+        let round_data = self.round_data()?;
+        let hint = Hint::new(level, round_data.rules, &round_data.word_data.secret)?;
+
+        let stats = &mut self.stats[self.current_round];
+        stats.hints = stats.hints.max(level + 1);
+
+        Some(hint)
+    }
+
+    /// How each round has gone so far.
+    pub fn stats(&self) -> &[RoundStats] {
+        &self.stats
+    }
+
+    /// Scores the game so far. The round being played counts as unsolved, but its time is counted up to now.
+    pub fn scorecard(&self, formula: &ScoreFormula) -> Scorecard {
+        let mut stats = self.stats.clone();
+        if let Some(stats) = stats.get_mut(self.current_round) {
+            stats.elapsed += self.round_elapsed();
+        }
+
+        Scorecard::new(&stats, self.current_round, formula)
+    }
+
+    /// Saves the game as it is, so it can be [loaded](Game::load) to pick up where it left off.
+    pub fn save(&self) -> String {
+        let mut stats = self.stats.clone();
+        if let Some(stats) = stats.get_mut(self.current_round) {
+            stats.elapsed += self.round_elapsed();
+        }
+
+        let save = SaveFile {
+            version: SAVE_VERSION,
+            rules: self.rules.clone(),
+            words: self.words.clone(),
+            chains: self.chains.clone(),
+            language: (&self.language).into(),
+            mode: self.mode,
+            date: self.date,
+            generator: self.generator.clone(),
+            best_depth: self.best_depth,
+            current_round: self.current_round,
+            stats,
+        };
+
+        serde_json::to_string(&save).expect("games can always be saved")
+    }
+
+    /// Loads a game from a [save](Game::save). The clock starts over, but the current round keeps the time it had.
+    pub fn load(txt: &str) -> Result<Self, LoadError> {
+        let version = serde_json::from_str::<SaveVersion>(txt)?.version;
+        if version > SAVE_VERSION {
+            return Err(LoadError::Version(version));
+        }
+        let save: SaveFile = serde_json::from_str(txt)?;
+
+        let len = save.words.len();
+        if save.chains.len() != len
+            || save.stats.len() != len
+            || save.current_round > len
+            || save.chains.iter().any(|v| v.start > v.end || v.end > save.rules.len())
+        {
+            return Err(LoadError::Corrupt);
+        }
+
+        let language = save.language.try_into()?;
+        let mut game = Self::from_rounds(save.rules, save.words, language).with_mode(save.mode);
+        game.chains = save.chains;
+        game.date = save.date;
+        game.generator = save.generator;
+        game.best_depth = save.best_depth;
+        game.current_round = save.current_round;
+        game.stats = save.stats;
+
+        Ok(game)
+    }
+
     pub fn round_data(&self) -> Option<RoundData<'_>> {
         if self.len == self.current_round {
             return None;
         }
         let word_data = &self.words[self.current_round];
-        let rules = &self.rules[0..=self.current_round];
+        let rules = &self.rules[self.chains[self.current_round].clone()];
 
         Some(RoundData { rules, word_data })
     }
@@ -74,7 +582,7 @@ impl Game {
         let mut words = vec![WordData::default(); len];
 
         for (i, instruction) in instructions.iter().enumerate() {
-            rules[i] = instruction.rule;
+            rules[i] = instruction.rule.clone();
 
             let mut hard_word = instruction.word.to_string();
             for rule in rules[0..=i].iter() {
@@ -88,12 +596,7 @@ impl Game {
             words[i] = word_data;
         }
 
-        Self {
-            rules,
-            words,
-            len,
-            current_round: 0,
-        }
+        Self::from_rounds(rules, words, Language::english())
     }
 
     pub fn iter(&self) -> Iter<'_> {
@@ -107,17 +610,52 @@ impl Default for Game {
     }
 }
 
+#[derive(Serialize)]
 pub struct RoundData<'a> {
     pub rules: &'a [Rule],
     pub word_data: &'a WordData,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WordData {
     pub secret: String,
     pub hard_word: String,
 }
 
+/// What came of a guess.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessOutcome {
+    /// The guess was the secret.
+    Correct,
+    /// The guess is a real word, but the rules turn it into this hard word instead.
+    ValidButWrong(String),
+    /// The guess isn't in the game's word list.
+    NotAWord,
+    /// The guess isn't the secret, but the rules turn it into the same hard word, so it's just as good.
+    AlternativeSolution,
+    /// The round ran out of time before the guess came in, so it's failed.
+    TimeUp,
+}
+
+impl GuessOutcome {
+    /// Checks if the guess solves the round.
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, GuessOutcome::Correct | GuessOutcome::AlternativeSolution)
+    }
+}
+
+/// How the player has done on a single round.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundStats {
+    pub attempts: usize,
+    /// How many levels of hints were taken, so 0 is none and 1 is only the length.
+    pub hints: usize,
+    /// How long the round took, or has taken so far.
+    pub elapsed: Duration,
+    /// If the round ran out of time.
+    pub failed: bool,
+}
+
 pub struct Iter<'a> {
     game: &'a Game,
     cursor: usize,
@@ -129,7 +667,7 @@ impl<'a> Iterator for Iter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.cursor < self.game.len() {
             let word_data = &self.game.words[self.cursor];
-            let rules = &self.game.rules[0..=self.cursor];
+            let rules = &self.game.rules[self.game.chains[self.cursor].clone()];
             self.cursor += 1;
 
             Some(RoundData { rules, word_data })
@@ -142,3 +680,439 @@ impl<'a> Iterator for Iter<'a> {
         (self.game.len - self.cursor, Some(self.game.len - self.cursor))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alphabet, ManualClock, Target, WordList};
+
+    #[test]
+    fn unique_rounds() {
+        let game = Game::unique_from_seed(1);
+        assert_eq!(game.len(), 8);
+        assert!(game.ambiguous_rounds().is_empty());
+
+        for (round, round_data) in game.iter().enumerate() {
+            let word_data = round_data.word_data;
+            assert_eq!(
+                solve_in(round_data.rules, &word_data.hard_word, &WordList::embedded()),
+                [word_data.secret.as_str()]
+            );
+
+            let before = apply_all(&round_data.rules[..round], &word_data.secret);
+            assert!(gen::is_noop(&round_data.rules[round], &before) == false);
+        }
+    }
+
+    #[test]
+    fn words_with_nothing_to_target() {
+        // these all pick words with no letters left to target at some point
+        for (seed, config, language) in [
+            (148, GeneratorConfig::default(), Language::spanish()),
+            (140, GeneratorConfig::default(), Language::french()),
+            (207, GeneratorConfig::default(), Language::french()),
+            (236, GeneratorConfig::default(), Language::french()),
+            (
+                170,
+                GeneratorConfig {
+                    unique: false,
+                    ..GeneratorConfig::hard()
+                },
+                Language::english(),
+            ),
+        ] {
+            let game = Game::from_seed_with_language(seed, &config, &language);
+            assert_eq!(game.len(), config.rounds);
+        }
+    }
+
+    #[test]
+    fn one_letter_words() {
+        let language = Language::from_words("test", Alphabet::english(), "a\nbe\n");
+        let config = GeneratorConfig {
+            word_len: 1..=2,
+            ..GeneratorConfig::default()
+        };
+
+        for seed in 0..20 {
+            assert_eq!(
+                Game::from_seed_with_language(seed, &config, &language).len(),
+                config.rounds
+            );
+        }
+    }
+
+    #[test]
+    fn too_few_words_for_unique_rounds() {
+        // cat and dog can always be told apart, but ab and ba soon can't be
+        for (words, ambiguous) in [("cat\ndog\n", false), ("ab\nba\n", true)] {
+            let language = Language::from_words("test", Alphabet::english(), words);
+            let game = Game::from_seed_with_language(1, &GeneratorConfig::medium(), &language);
+
+            assert_eq!(game.len(), GeneratorConfig::medium().rounds);
+            assert_eq!(game.ambiguous_rounds().is_empty(), ambiguous == false, "{:?}", words);
+            for (round, round_data) in game.iter().enumerate() {
+                // there's nothing left to change once the rules before take every letter away
+                let before = apply_all(&round_data.rules[..round], &round_data.word_data.secret);
+                assert!(gen::is_noop(&round_data.rules[round], &before) == false || before.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        fn rounds(game: &Game) -> Vec<(Vec<Rule>, String, String)> {
+            game.iter()
+                .map(|r| {
+                    (
+                        r.rules.to_vec(),
+                        r.word_data.secret.clone(),
+                        r.word_data.hard_word.clone(),
+                    )
+                })
+                .collect()
+        }
+
+        assert_eq!(rounds(&Game::from_seed(42)), rounds(&Game::from_seed(42)));
+        assert_eq!(rounds(&Game::unique_from_seed(7)), rounds(&Game::unique_from_seed(7)));
+        assert_ne!(rounds(&Game::from_seed(42)), rounds(&Game::from_seed(43)));
+    }
+
+    #[test]
+    fn targets_difficulty() {
+        let easiest = Game::from_seed_with_config(
+            5,
+            &GeneratorConfig {
+                difficulty: Some(0.0..=0.0),
+                unique: false,
+                ..GeneratorConfig::easy()
+            },
+        );
+        let hardest = Game::from_seed_with_config(
+            5,
+            &GeneratorConfig {
+                difficulty: Some(1000.0..=1000.0),
+                unique: false,
+                ..GeneratorConfig::easy()
+            },
+        );
+
+        assert!(easiest.difficulty() < hardest.difficulty());
+    }
+
+    #[test]
+    fn follows_config() {
+        for config in [
+            GeneratorConfig::easy(),
+            GeneratorConfig::medium(),
+            GeneratorConfig::hard(),
+        ] {
+            let game = Game::from_seed_with_config(3, &config);
+            assert_eq!(game.len(), config.rounds);
+
+            for (round, round_data) in game.iter().enumerate() {
+                let rule = &round_data.rules[round];
+                assert!(config
+                    .weights(round)
+                    .0
+                    .iter()
+                    .any(|(kind, weight)| *kind == rule.kind() && *weight > 0));
+                assert!(config.word_len.contains(&round_data.word_data.secret.chars().count()));
+
+                if let Rule::Duplicate(dup) = rule {
+                    assert!(config.duplicate_count.contains(&dup.count));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn other_languages() {
+        for language in [Language::spanish(), Language::german(), Language::french()] {
+            let game = Game::from_seed_with_language(11, &GeneratorConfig::medium(), &language);
+            assert_eq!(game.language(), &language);
+
+            for round_data in game.iter() {
+                let word_data = round_data.word_data;
+                assert!(language.words.contains(&word_data.secret));
+                assert_eq!(
+                    solve_in(round_data.rules, &word_data.hard_word, &language.words),
+                    [word_data.secret.as_str()]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn destinations() {
+        let english = Language::english();
+        let mut config = GeneratorConfig::default();
+        assert!(gen::destinations(&config, &english, &Target::Char('e'), "tree").contains(&'z'));
+        assert!(gen::destinations(&config, &english, &Target::Char('e'), "tree").contains(&'e') == false);
+
+        config.exclude.present = true;
+        assert_eq!(
+            gen::destinations(&config, &english, &Target::Char('e'), "tree").len(),
+            23
+        );
+
+        config.destinations = vec!['e', 'r', 'x'];
+        assert_eq!(gen::destinations(&config, &english, &Target::Char('e'), "tree"), ['x']);
+        // everything being excluded would leave us stuck, so nothing is
+        assert_eq!(
+            gen::destinations(&config, &english, &Target::Vowel, "extra"),
+            ['e', 'r', 'x']
+        );
+    }
+
+    #[test]
+    fn destinations_are_excluded() {
+        let config = GeneratorConfig {
+            unique: false,
+            ..GeneratorConfig::easy()
+        };
+
+        for seed in 0..20 {
+            let game = Game::from_seed_with_config(seed, &config);
+            for (round, round_data) in game.iter().enumerate() {
+                let before = apply_all(&round_data.rules[..round], &round_data.word_data.secret);
+                let rule = &round_data.rules[round];
+
+                if let Rule::Convert(cnv) = rule {
+                    assert!(before.contains(cnv.destination) == false, "{} on {}", rule, before);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn guesses() {
+        let rules = vec![Rule::convert('e', 'a')];
+        let words = vec![WordData {
+            secret: "better".to_string(),
+            hard_word: apply_all(&rules, "better"),
+        }];
+        let language = Language::from_words("test", crate::Alphabet::english(), "better\nbatter\nbitter\n");
+        let mut game = Game::from_rounds(rules, words, language);
+
+        assert_eq!(game.submit_guess("bitxer"), GuessOutcome::NotAWord);
+        assert_eq!(
+            game.submit_guess("bitter"),
+            GuessOutcome::ValidButWrong("bittar".to_string())
+        );
+        assert_eq!(game.check_guess("Batter "), GuessOutcome::AlternativeSolution);
+        assert_eq!(game.answers(), ["better", "batter"]);
+        assert_eq!(game.stats()[0].attempts, 1);
+
+        assert_eq!(game.hint(2), Some(Hint::Letter(1, 'e')));
+        assert_eq!(game.hint(0), Some(Hint::Length(6)));
+        assert_eq!(game.hint(Hint::LEVELS), None);
+        // undoing the only rule would be the answer
+        assert_eq!(game.hint(3), None);
+        assert_eq!(game.stats()[0].hints, 3);
+
+        assert_eq!(game.submit_guess("better"), GuessOutcome::Correct);
+        assert_eq!(game.stats()[0].attempts, 2);
+        assert!(game.round_data().is_none());
+    }
+
+    #[test]
+    fn lives() {
+        let rules = vec![Rule::convert('e', 'a'), Rule::remove('t')];
+        let words: Vec<WordData> = ["better", "letter"]
+            .iter()
+            .enumerate()
+            .map(|(i, secret)| WordData {
+                secret: secret.to_string(),
+                hard_word: apply_all(&rules[..=i], secret),
+            })
+            .collect();
+        let language = Language::from_words("test", crate::Alphabet::english(), "better\nletter\nbitter\n");
+
+        let mut game = Game::from_rounds(rules.clone(), words.clone(), language.clone()).with_mode(GameMode::Lives(2));
+        assert_eq!(game.lives_left(), Some(2));
+        game.submit_guess("bitter");
+        game.submit_guess("nope");
+        game.submit_guess("better");
+        assert_eq!(game.lives_left(), Some(1));
+        game.submit_guess("bitter");
+        assert_eq!(game.state(), GameState::Lost);
+        game.submit_guess("letter");
+        assert_eq!(game.current_round(), 1);
+
+        let mut game = Game::from_rounds(rules, words, language).with_mode(GameMode::LivesPerRound(2));
+        game.submit_guess("bitter");
+        game.submit_guess("better");
+        assert_eq!(game.lives_left(), Some(2));
+        game.submit_guess("bitter");
+        assert_eq!(game.state(), GameState::InProgress);
+        game.submit_guess("letter");
+        assert_eq!(game.state(), GameState::Won);
+        assert_eq!(Game::from_seed(1).lives_left(), None);
+    }
+
+    #[test]
+    fn time_attack() {
+        let clock = ManualClock::new();
+        let mut game = Game::from_seed(3)
+            .with_mode(GameMode::TimeAttack(Duration::from_secs(30)))
+            .with_clock(clock.clone());
+        assert_eq!(game.time_left(), Some(Duration::from_secs(30)));
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(game.time_left(), Some(Duration::from_secs(20)));
+        assert!(game.tick() == false);
+
+        let secret = game.round_data().unwrap().word_data.secret.clone();
+        assert!(game.submit_guess(&secret).is_accepted());
+        assert_eq!(game.stats()[0].elapsed, Duration::from_secs(10));
+
+        clock.advance(Duration::from_secs(30));
+        let secret = game.round_data().unwrap().word_data.secret.clone();
+        assert_eq!(game.submit_guess(&secret), GuessOutcome::TimeUp);
+        assert!(game.stats()[1].failed);
+        assert_eq!(game.current_round(), 2);
+
+        // hints don't stop the clock either
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(game.hint(0), None);
+        assert!(game.stats()[2].failed);
+        assert_eq!(game.stats()[2].hints, 0);
+        assert_eq!(game.current_round(), 3);
+
+        let card = game.scorecard(&ScoreFormula::default());
+        assert!(card.rounds[0].solved);
+        assert!(card.rounds[1].solved == false);
+
+        while game.state() == GameState::InProgress {
+            clock.advance(Duration::from_secs(31));
+            assert!(game.tick());
+        }
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(game.time_left(), None);
+    }
+
+    #[test]
+    fn endless() {
+        for overflow in [Overflow::Stack, Overflow::Rotate] {
+            let endless = Endless { max_rules: 3, overflow };
+            let mut game = Game::endless_from_seed(9, &GeneratorConfig::medium(), &Language::english(), endless)
+                .with_best_depth(4);
+            assert_eq!(game.len(), 1);
+
+            let mut chains = vec![];
+            for _ in 0..6 {
+                let round_data = game.round_data().unwrap();
+                assert_eq!(
+                    apply_all(round_data.rules, &round_data.word_data.secret),
+                    round_data.word_data.hard_word
+                );
+                chains.push(round_data.rules.to_vec());
+
+                let secret = round_data.word_data.secret.clone();
+                assert!(game.submit_guess(&secret).is_accepted());
+            }
+
+            assert_eq!(game.len(), 7);
+            assert_eq!(game.state(), GameState::InProgress);
+            assert_eq!(game.best_depth(), 6);
+            assert!(game.ambiguous_rounds().is_empty());
+
+            let lens: Vec<usize> = chains.iter().map(|v| v.len()).collect();
+            assert_eq!(lens, [1, 2, 3, 3, 3, 3]);
+            match overflow {
+                Overflow::Stack => assert_eq!(chains[2], chains[5]),
+                Overflow::Rotate => assert_eq!(chains[2][1..], chains[3][..2]),
+            }
+        }
+    }
+
+    #[test]
+    fn endless_runs_out_of_targets() {
+        // two letters only go so far before every letter is a target
+        let language = Language::from_words("test", Alphabet::english(), "aabb\nabab\nbbaa\nbaba\n");
+        let config = GeneratorConfig {
+            word_len: 4..=4,
+            ..GeneratorConfig::medium()
+        };
+        let mut game = Game::endless_from_seed(1, &config, &language, Endless::default());
+
+        for _ in 0..10 {
+            let secret = game.round_data().unwrap().word_data.secret.clone();
+            assert!(game.submit_guess(&secret).is_accepted());
+        }
+        assert_eq!(game.current_round(), 10);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let clock = ManualClock::new();
+        let mut game = Game::from_seed_with_language(4, &GeneratorConfig::medium(), &Language::german())
+            .with_mode(GameMode::TimeAttack(Duration::from_secs(60)))
+            .with_clock(clock.clone());
+        let secret = game.round_data().unwrap().word_data.secret.clone();
+        game.submit_guess(&secret);
+        game.hint(1);
+        game.submit_guess(&secret);
+        clock.advance(Duration::from_secs(20));
+
+        let mut loaded = Game::load(&game.save()).unwrap().with_clock(ManualClock::new());
+        assert_eq!(loaded.current_round(), 1);
+        assert_eq!(loaded.mode(), game.mode());
+        assert_eq!(loaded.language(), game.language());
+        assert_eq!(loaded.stats()[1].hints, 2);
+        assert_eq!(loaded.stats()[1].attempts, 1);
+        assert_eq!(loaded.time_left(), Some(Duration::from_secs(40)));
+        for (a, b) in game.iter().zip(loaded.iter()) {
+            assert_eq!(a.rules, b.rules);
+            assert_eq!(a.word_data, b.word_data);
+        }
+
+        let secret = loaded.round_data().unwrap().word_data.secret.clone();
+        assert!(loaded.submit_guess(&secret).is_accepted());
+
+        // built in languages are saved by their code, and the rest with all their words
+        let save: serde_json::Value = serde_json::from_str(&game.save()).unwrap();
+        assert_eq!(save["language"], "de");
+        let custom = Language::from_words("test", Alphabet::english(), "cat\ndog\n");
+        let save = Game::from_rounds(vec![], vec![], custom.clone()).save();
+        assert_eq!(Game::load(&save).unwrap().language(), &custom);
+
+        let daily = Game::daily(crate::Date::new(2026, 10, 18));
+        assert_eq!(Game::load(&daily.save()).unwrap().date(), daily.date());
+
+        // endless games keep on making the same rounds
+        let mut endless =
+            Game::endless_from_seed(2, &GeneratorConfig::easy(), &Language::english(), Endless::default());
+        let secret = endless.round_data().unwrap().word_data.secret.clone();
+        endless.submit_guess(&secret);
+        let mut loaded = Game::load(&endless.save()).unwrap();
+        for game in [&mut endless, &mut loaded] {
+            let secret = game.round_data().unwrap().word_data.secret.clone();
+            game.submit_guess(&secret);
+        }
+        assert_eq!(
+            endless.round_data().unwrap().word_data,
+            loaded.round_data().unwrap().word_data
+        );
+    }
+
+    #[test]
+    fn bad_saves() {
+        let save = Game::from_seed(1).save();
+
+        let newer = save.replacen(
+            &format!("\"version\":{}", SAVE_VERSION),
+            &format!("\"version\":{}", SAVE_VERSION + 1),
+            1,
+        );
+        assert!(matches!(Game::load(&newer), Err(LoadError::Version(_))));
+        assert!(matches!(Game::load("{}"), Err(LoadError::Json(_))));
+
+        let corrupt = save.replacen("\"current_round\":0", "\"current_round\":99", 1);
+        assert!(matches!(Game::load(&corrupt), Err(LoadError::Corrupt)));
+
+        let unknown = save.replacen("\"language\":\"en\"", "\"language\":\"xx\"", 1);
+        assert!(matches!(Game::load(&unknown), Err(LoadError::Language(_))));
+    }
+}
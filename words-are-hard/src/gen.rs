@@ -2,97 +2,252 @@ use rand::prelude::*;
 
 use crate::*;
 
-/// Generates a new backing word
-pub fn generate_word(
-    rng: &mut ThreadRng,
+/// Generates a new backing word from the list, within the config's word length bounds. Panics if no word is.
+pub fn generate_word<'a, R: Rng + ?Sized>(
+    config: &GeneratorConfig,
+    words: &'a WordList,
+    rng: &mut R,
     previous_rules: &[Rule],
-) -> (&'static str, impl Iterator<Item = char> + Clone) {
+) -> (&'a str, impl Iterator<Item = char> + Clone) {
     let round = previous_rules.len();
 
     // these are our choices tyo
-    let choices: std::collections::HashSet<_> = previous_rules.iter().map(|v| v.target()).collect();
+    let choices: Vec<Target> = previous_rules.iter().filter_map(|v| v.target()).collect();
+    let chosen = move |chr: char| choices.iter().any(|v| v.matches(chr));
 
-    let word = if round < 5 {
-        *WORDS.choose(rng).unwrap()
+    let fits = |w: &&String| config.word_len.contains(&w.chars().count());
+
+    // make it a pipelined selection...
+    let pipelined = if round < 5 {
+        None
     } else {
-        // make it a pipelined selection...
-        WORDS
+        words.iter().filter(fits).filter(|w| w.chars().any(&chosen)).choose(rng)
+    };
+
+    let word = match pipelined {
+        Some(word) => word,
+        None => words
             .iter()
-            .filter(|w| w.chars().any(|chr| choices.contains(&chr)))
+            .filter(fits)
             .choose(rng)
-            .unwrap()
+            .expect("no words fit within the word length bounds"),
     };
 
     let selection: Vec<_> = if round < 2 {
+        // a one letter word is its own first and last letter
         let mut chars = word.chars();
-        vec![chars.next().unwrap(), chars.last().unwrap()]
+        let first = chars.next().unwrap();
+        vec![first, chars.last().unwrap_or(first)]
     } else {
-        word.chars().filter(move |v| choices.contains(v) == false).collect()
+        word.chars().filter(|v| chosen(*v) == false).collect()
     };
 
     (word, selection.into_iter())
 }
 
-/// Generates a new rule
-pub fn generate_rule(round: usize, rng: &mut ThreadRng, choices: impl Iterator<Item = char> + Clone) -> Rule {
-    // okay if we're in the first three rounds, we ALWAYS do a conversion rule...
-    if round < 3 {
-        let target = choices.choose(rng).unwrap();
-        let replace_with = rng.gen_range(LOWERCASE_CHARS);
+/// How many games are generated while looking for one in the config's difficulty band.
+pub const DIFFICULTY_ATTEMPTS: usize = 16;
+
+/// How many words and rules `generate_unique_round` tries before giving up.
+pub const UNIQUE_ATTEMPTS: usize = 200;
+
+/// How many times a game with unique rounds starts over before settling for rounds which might not be unique.
+pub const UNIQUE_RESTARTS: usize = 10;
+
+/// Generates a new backing word and rule, rejecting rounds where the rule does nothing to the word or where
+/// another of the language's words would be an equally good answer.
+///
+/// Returns `None` if nothing good turned up in [`UNIQUE_ATTEMPTS`] tries. The previous rules might be to blame
+/// for that, so callers should start over.
+pub fn generate_unique_round<'a, R: Rng + ?Sized>(
+    config: &GeneratorConfig,
+    language: &'a Language,
+    rng: &mut R,
+    previous_rules: &[Rule],
+) -> Option<(&'a str, Rule)> {
+    let round = previous_rules.len();
+
+    for _ in 0..UNIQUE_ATTEMPTS {
+        let (secret, selection) = generate_word(config, &language.words, rng, previous_rules);
+
+        // `generate_rule` needs something to target
+        if selection.clone().next().is_none() {
+            continue;
+        }
+        let word = apply_all(previous_rules, secret);
+        let rule = generate_rule(config, language, round, rng, &word, selection);
+
+        if is_noop(&rule, &word) {
+            continue;
+        }
+
+        let mut rules = previous_rules.to_vec();
+        let hard_word = rule.apply(&word);
+        rules.push(rule.clone());
+
+        if solve_in(&rules, &hard_word, &language.words) == [secret] {
+            return Some((secret, rule));
+        }
+    }
+
+    None
+}
+
+/// Generates a new backing word and rule, without any of the checks of [`generate_unique_round`]. Words which leave
+/// nothing for the rule to target are passed over, and if only those turn up in [`UNIQUE_ATTEMPTS`] tries, the rule
+/// targets any letter left in the word.
+pub fn generate_round<'a, R: Rng + ?Sized>(
+    config: &GeneratorConfig,
+    language: &'a Language,
+    rng: &mut R,
+    previous_rules: &[Rule],
+) -> (&'a str, Rule) {
+    let round = previous_rules.len();
+
+    for _ in 0..UNIQUE_ATTEMPTS {
+        let (secret, selection) = generate_word(config, &language.words, rng, previous_rules);
+
+        if selection.clone().next().is_some() {
+            let word = apply_all(previous_rules, secret);
+            return (secret, generate_rule(config, language, round, rng, &word, selection));
+        }
+    }
+
+    let (secret, _) = generate_word(config, &language.words, rng, previous_rules);
+    let word = apply_all(previous_rules, secret);
+    // the rules before might have taken every letter away
+    let choices: Vec<char> = if word.is_empty() {
+        secret.chars().collect()
+    } else {
+        word.chars().collect()
+    };
 
-        let cnv = Rule::Convert(TargetDestination {
-            target,
-            destination: replace_with,
-        });
+    (
+        secret,
+        generate_rule(config, language, round, rng, &word, choices.into_iter()),
+    )
+}
 
-        return cnv;
+/// Like [`generate_round`], but passes over rules which do nothing to the word for up to [`UNIQUE_ATTEMPTS`] tries.
+pub fn generate_changing_round<'a, R: Rng + ?Sized>(
+    config: &GeneratorConfig,
+    language: &'a Language,
+    rng: &mut R,
+    previous_rules: &[Rule],
+) -> (&'a str, Rule) {
+    for _ in 1..UNIQUE_ATTEMPTS {
+        let (secret, rule) = generate_round(config, language, rng, previous_rules);
+        if is_noop(&rule, &apply_all(previous_rules, secret)) == false {
+            return (secret, rule);
+        }
     }
 
-    // on round 4, we *always* generate a weird rule...
-    if round == 4 {
-        let target = choices.clone().choose(rng).unwrap();
-
-        return match rng.gen_range(0..=2) {
-            0 => {
-                let count = rng.gen_range(2..5);
-
-                Rule::Duplicate(Duplicate { target, count })
-            }
-            1 => Rule::Remove(Remove(target)),
-            2 => Rule::Switch(TargetDestination {
-                target,
-                destination: choices.choose(rng).unwrap(),
-            }),
-            _ => unimplemented!(),
-        };
+    generate_round(config, language, rng, previous_rules)
+}
+
+/// Generates a new backing word for rules which are already set, rejecting words which another of the language's
+/// words would be an equally good answer for if the config asks for unique rounds.
+///
+/// If nothing good turns up in [`UNIQUE_ATTEMPTS`] tries, settles for any word.
+pub fn generate_secret<'a, R: Rng + ?Sized>(
+    config: &GeneratorConfig,
+    language: &'a Language,
+    rng: &mut R,
+    rules: &[Rule],
+) -> &'a str {
+    let attempts = if config.unique { UNIQUE_ATTEMPTS } else { 1 };
+
+    for _ in 1..attempts {
+        let (secret, _) = generate_word(config, &language.words, rng, rules);
+        if solve_in(rules, &apply_all(rules, secret), &language.words) == [secret] {
+            return secret;
+        }
     }
 
-    // on subsequent rounds, we pick a random rule...
-    let number: usize = rng.gen_range(0..10);
-    match number {
-        0..=4 => {
-            let target = choices.choose(rng).unwrap();
-            let replace_with = rng.gen_range(LOWERCASE_CHARS);
-
-            Rule::Convert(TargetDestination {
-                target,
-                destination: replace_with,
-            })
+    generate_word(config, &language.words, rng, rules).0
+}
+
+/// Checks if a rule leaves the word exactly as it found it, such as a `Switch` with the same target and
+/// destination, or a `Convert` of a letter the word doesn't have.
+pub fn is_noop(rule: &Rule, word: &str) -> bool {
+    rule.apply(word) == word
+}
+
+/// Generates a new rule for the word, picking its kind from the config's schedule for the round, its target from
+/// `choices` and any new letters from its [`destinations`]. Panics if there are no choices.
+pub fn generate_rule<R: Rng + ?Sized>(
+    config: &GeneratorConfig,
+    language: &Language,
+    round: usize,
+    rng: &mut R,
+    word: &str,
+    choices: impl Iterator<Item = char> + Clone,
+) -> Rule {
+    let target = choices.clone().choose(rng).unwrap();
+    let letters = destinations(config, language, &Target::Char(target), word);
+    let letter = |rng: &mut R| *letters.choose(rng).expect("there are no letters to pick from");
+
+    match config.weights(round).choose(rng) {
+        RuleKind::Convert => Rule::convert(target, letter(rng)).with_occurrence(generate_occurrence(config, rng)),
+        RuleKind::Duplicate => Rule::duplicate(target, rng.gen_range(config.duplicate_count.clone()))
+            .with_occurrence(generate_occurrence(config, rng)),
+        RuleKind::Remove => Rule::remove(target).with_occurrence(generate_occurrence(config, rng)),
+        RuleKind::Insert => {
+            let side = if rng.gen_bool(0.5) { Side::Before } else { Side::After };
+
+            Rule::insert(target, letter(rng), side).with_occurrence(generate_occurrence(config, rng))
+        }
+        RuleKind::Switch => {
+            let others = choices
+                .clone()
+                .filter(|chr| config.exclude.target == false || *chr != target);
+            let destination = others.choose(rng).or_else(|| choices.choose(rng)).unwrap();
+
+            Rule::switch(target, destination)
         }
-        5..=6 => Rule::Switch(TargetDestination {
-            target: choices.clone().choose(rng).unwrap(),
-            destination: choices.choose(rng).unwrap(),
+        RuleKind::Reverse => Rule::Reverse(Reverse {
+            between: rng.gen_bool(0.5).then_some(target),
         }),
-        7..=8 => {
-            let target = choices.choose(rng).unwrap();
-            let count = rng.gen_range(2..5);
+        RuleKind::Shift => {
+            let class = *LetterClass::ALL.choose(rng).unwrap();
+            let amount = rng.gen_range(1..class.letters().len());
 
-            Rule::Duplicate(Duplicate { target, count })
+            Rule::Shift(Shift { class, amount })
         }
-        9 => {
-            let target = choices.choose(rng).unwrap();
-            Rule::Remove(Remove(target))
+        RuleKind::Swap => {
+            let positions = rand::seq::index::sample(rng, word.chars().count().max(2), 2);
+
+            Rule::swap(positions.index(0), positions.index(1))
         }
-        _ => unimplemented!(),
     }
 }
+
+/// The letters a rule on `target` could bring into the word: the config's destinations, or the language's alphabet
+/// if it has none, minus the config's exclusions. If that excludes everything, nothing is excluded.
+pub fn destinations(config: &GeneratorConfig, language: &Language, target: &Target, word: &str) -> Vec<char> {
+    let all = if config.destinations.is_empty() {
+        &language.alphabet.letters
+    } else {
+        &config.destinations
+    };
+    let allowed: Vec<char> = all
+        .iter()
+        .copied()
+        .filter(|chr| config.exclude.allows(*chr, target, word))
+        .collect();
+
+    if allowed.is_empty() {
+        all.clone()
+    } else {
+        allowed
+    }
+}
+
+/// Picks which targets a rule acts on from the config's occurrences.
+fn generate_occurrence<R: Rng + ?Sized>(config: &GeneratorConfig, rng: &mut R) -> Occurrence {
+    config
+        .occurrences
+        .choose_weighted(rng, |(_, weight)| *weight)
+        .map(|(occurrence, _)| *occurrence)
+        .unwrap_or_default()
+}
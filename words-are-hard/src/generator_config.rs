@@ -0,0 +1,204 @@
+use std::ops::RangeInclusive;
+
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::{Occurrence, RuleKind, Target};
+
+/// How likely each kind of rule is to be picked. Kinds which aren't listed, or have a weight of 0, are never picked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleWeights(pub Vec<(RuleKind, u32)>);
+
+impl RuleWeights {
+    /// Only ever picks the one kind.
+    pub fn only(kind: RuleKind) -> Self {
+        Self(vec![(kind, 1)])
+    }
+
+    /// Picks a kind of rule. Panics if nothing has any weight.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> RuleKind {
+        self.0
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .expect("rule weights must have at least one positive weight")
+            .0
+    }
+}
+
+/// Letters a rule is never allowed to bring into the word.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Exclusions {
+    /// Never turns a letter into one its rule targets, like converting e to e.
+    pub target: bool,
+    /// Never brings in a letter the word already has.
+    pub present: bool,
+    /// Never brings in any of these.
+    pub letters: Vec<char>,
+}
+
+impl Exclusions {
+    /// Excludes letters equal to the target, but nothing else.
+    pub fn target() -> Self {
+        Self {
+            target: true,
+            ..Self::default()
+        }
+    }
+
+    /// Checks if a rule on `target` is allowed to bring `chr` into `word`.
+    pub fn allows(&self, chr: char, target: &Target, word: &str) -> bool {
+        (self.target && target.matches(chr)) == false
+            && (self.present && word.contains(chr)) == false
+            && self.letters.contains(&chr) == false
+    }
+}
+
+/// Everything that goes into generating a random game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneratorConfig {
+    /// How many rounds, and therefore rules, the game has.
+    pub rounds: usize,
+    /// The rules allowed in each round, by index. Rounds past the end use the last entry.
+    pub schedule: Vec<RuleWeights>,
+    /// How many times a `Duplicate` rule can duplicate its target.
+    pub duplicate_count: RangeInclusive<usize>,
+    /// How many chars a secret can have.
+    pub word_len: RangeInclusive<usize>,
+    /// How likely a `Convert`, `Duplicate`, `Remove` or `Insert` is to act on only some of its targets. If this is empty,
+    /// they always act on all of them.
+    #[serde(default)]
+    pub occurrences: Vec<(Occurrence, u32)>,
+    /// The letters a `Convert` or `Insert` can bring into the word. If this is empty, it's the language's alphabet.
+    #[serde(default)]
+    pub destinations: Vec<char>,
+    /// Letters which are left out of the destinations for each rule, so that rules always do something.
+    #[serde(default)]
+    pub exclude: Exclusions,
+    /// If set, every rule must change the word and every round must have exactly one answer in the word list. After
+    /// [`UNIQUE_RESTARTS`](crate::gen::UNIQUE_RESTARTS) tries at that, rounds which don't are let through.
+    pub unique: bool,
+    /// If set, the hardest round's [`difficulty`](crate::Game::difficulty) should be in this band. Games are generated
+    /// until one lands in it, settling for the closest after a few tries.
+    pub difficulty: Option<RangeInclusive<f32>>,
+}
+
+impl GeneratorConfig {
+    /// A short game of mostly conversions on shorter words.
+    pub fn easy() -> Self {
+        Self {
+            rounds: 6,
+            schedule: vec![
+                RuleWeights::only(RuleKind::Convert),
+                RuleWeights::only(RuleKind::Convert),
+                RuleWeights::only(RuleKind::Convert),
+                RuleWeights(vec![
+                    (RuleKind::Convert, 3),
+                    (RuleKind::Duplicate, 1),
+                    (RuleKind::Remove, 1),
+                ]),
+            ],
+            duplicate_count: 2..=2,
+            word_len: 9..=10,
+            occurrences: vec![],
+            destinations: vec![],
+            exclude: Exclusions {
+                target: true,
+                present: true,
+                letters: vec![],
+            },
+            unique: true,
+            difficulty: None,
+        }
+    }
+
+    /// The classic curve, but every round has exactly one answer.
+    pub fn medium() -> Self {
+        Self {
+            unique: true,
+            ..Self::default()
+        }
+    }
+
+    /// A long game which gets to the weird rules quickly.
+    pub fn hard() -> Self {
+        Self {
+            rounds: 10,
+            schedule: vec![
+                RuleWeights::only(RuleKind::Convert),
+                RuleWeights(vec![(RuleKind::Convert, 1), (RuleKind::Switch, 1)]),
+                RuleWeights(vec![
+                    (RuleKind::Duplicate, 1),
+                    (RuleKind::Remove, 1),
+                    (RuleKind::Switch, 1),
+                    (RuleKind::Reverse, 1),
+                ]),
+                RuleWeights(vec![
+                    (RuleKind::Convert, 3),
+                    (RuleKind::Duplicate, 2),
+                    (RuleKind::Remove, 2),
+                    (RuleKind::Switch, 3),
+                    (RuleKind::Reverse, 2),
+                    (RuleKind::Shift, 1),
+                    (RuleKind::Swap, 1),
+                    (RuleKind::Insert, 1),
+                ]),
+            ],
+            duplicate_count: 2..=4,
+            word_len: 11..=usize::MAX,
+            occurrences: vec![
+                (Occurrence::All, 6),
+                (Occurrence::First, 1),
+                (Occurrence::Last, 1),
+                (Occurrence::EveryOther, 1),
+            ],
+            destinations: vec![],
+            exclude: Exclusions::target(),
+            unique: true,
+            difficulty: None,
+        }
+    }
+
+    /// The rules allowed in the given round.
+    pub fn weights(&self, round: usize) -> &RuleWeights {
+        self.schedule
+            .get(round)
+            .or_else(|| self.schedule.last())
+            .expect("the schedule must have at least one round")
+    }
+}
+
+impl Default for GeneratorConfig {
+    /// The original curve: three rounds of conversions, a weird rule on the fifth round, and anything goes after
+    /// that.
+    fn default() -> Self {
+        let anything = RuleWeights(vec![
+            (RuleKind::Convert, 5),
+            (RuleKind::Switch, 2),
+            (RuleKind::Duplicate, 2),
+            (RuleKind::Remove, 1),
+        ]);
+
+        Self {
+            rounds: 8,
+            schedule: vec![
+                RuleWeights::only(RuleKind::Convert),
+                RuleWeights::only(RuleKind::Convert),
+                RuleWeights::only(RuleKind::Convert),
+                anything.clone(),
+                RuleWeights(vec![
+                    (RuleKind::Duplicate, 1),
+                    (RuleKind::Remove, 1),
+                    (RuleKind::Switch, 1),
+                ]),
+                anything,
+            ],
+            duplicate_count: 2..=4,
+            word_len: 1..=usize::MAX,
+            occurrences: vec![],
+            destinations: vec![],
+            exclude: Exclusions::target(),
+            unique: false,
+            difficulty: None,
+        }
+    }
+}
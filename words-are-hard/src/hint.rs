@@ -0,0 +1,109 @@
+use std::fmt;
+
+use crate::{apply_all, occurrence::ordinal, trace, Rule};
+
+/// A nudge towards a round's secret. Each level gives away more than the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    /// How many chars the secret has.
+    Length(usize),
+    /// Each char of the hard word, with the index of the last rule which did something to it, if any did.
+    Attribution(Vec<(char, Option<usize>)>),
+    /// One of the secret's chars, and its index.
+    Letter(usize, char),
+    /// The word before the last rule was executed.
+    Undo(String),
+}
+
+impl Hint {
+    /// How many levels of hints there are.
+    pub const LEVELS: usize = 4;
+
+    /// Works out the hint at the given level for a secret and the rules up to its round. Levels start at 0, and are
+    /// `None` past [`Hint::LEVELS`], and past level 2 when there's only one rule.
+    pub fn new(level: usize, rules: &[Rule], secret: &str) -> Option<Self> {
+        match level {
+            0 => Some(Hint::Length(secret.chars().count())),
+            1 => {
+                let trace = trace(rules, secret);
+                let attribution = trace
+                    .hard_word()
+                    .chars()
+                    .enumerate()
+                    .map(|(i, chr)| (chr, trace.history(i).last().map(|(rule, _)| *rule)))
+                    .collect();
+
+                Some(Hint::Attribution(attribution))
+            }
+            2 => {
+                // a letter the player can't just read off the hard word, if there is one
+                let hard_word: Vec<char> = apply_all(rules, secret).chars().collect();
+                let (index, chr) = secret
+                    .chars()
+                    .enumerate()
+                    .find(|(i, chr)| hard_word.get(*i) != Some(chr))
+                    .or_else(|| secret.chars().next().map(|chr| (0, chr)))?;
+
+                Some(Hint::Letter(index, chr))
+            }
+            // undoing the only rule would give the secret away
+            3 if rules.len() > 1 => Some(Hint::Undo(apply_all(&rules[..rules.len() - 1], secret))),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::Length(len) => write!(f, "The original word has {} letters", len),
+            Hint::Attribution(attribution) => {
+                write!(f, "The last rule to touch each letter:")?;
+                for (chr, rule) in attribution {
+                    match rule {
+                        Some(rule) => write!(f, " {}:{}", chr, rule + 1)?,
+                        None => write!(f, " {}:-", chr)?,
+                    }
+                }
+
+                Ok(())
+            }
+            Hint::Letter(index, chr) => write!(f, "The {} letter of the original word is {}", ordinal(index + 1), chr),
+            Hint::Undo(word) => write!(f, "Before the last rule, the word was {}", word),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        let rules = [Rule::convert('e', 'a'), Rule::remove('t')];
+
+        assert_eq!(Hint::new(0, &rules, "better"), Some(Hint::Length(6)));
+        assert_eq!(
+            Hint::new(1, &rules, "better"),
+            Some(Hint::Attribution(vec![
+                ('b', None),
+                ('a', Some(0)),
+                ('a', Some(0)),
+                ('r', None)
+            ]))
+        );
+        assert_eq!(Hint::new(2, &rules, "better"), Some(Hint::Letter(1, 'e')));
+        assert_eq!(Hint::new(3, &rules, "better"), Some(Hint::Undo("battar".to_string())));
+        assert_eq!(Hint::new(Hint::LEVELS, &rules, "better"), None);
+        assert_eq!(Hint::new(3, &rules[..1], "better"), None);
+
+        assert_eq!(
+            Hint::new(1, &rules, "better").unwrap().to_string(),
+            "The last rule to touch each letter: b:- a:1 a:1 r:-"
+        );
+        assert_eq!(
+            Hint::new(2, &rules, "better").unwrap().to_string(),
+            "The 2nd letter of the original word is e"
+        );
+    }
+}
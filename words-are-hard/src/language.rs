@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{WordList, LOWERCASE_CHARS};
+
+/// The letters a language is written with. Rules which target vowels, consonants or any letter only know about `a`
+/// to `z`, so the extra letters are left alone by them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Alphabet {
+    pub letters: Vec<char>,
+}
+
+impl Alphabet {
+    /// Makes an alphabet out of `a` to `z` and some extra letters.
+    fn latin(extra_letters: &str) -> Self {
+        Self {
+            letters: LOWERCASE_CHARS.chain(extra_letters.chars()).collect(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Self::latin("")
+    }
+
+    pub fn spanish() -> Self {
+        Self::latin("ñáéíóúü")
+    }
+
+    pub fn german() -> Self {
+        Self::latin("äöüß")
+    }
+
+    pub fn french() -> Self {
+        Self::latin("àâæçéèêëîïôœùûüÿ")
+    }
+
+    pub fn contains(&self, chr: char) -> bool {
+        self.letters.contains(&chr)
+    }
+}
+
+/// A language to play in: its alphabet, and the words secrets are picked from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Language {
+    pub name: String,
+    /// The code of a built in language, like `en`. Saves only keep the code of a language which has one, rather
+    /// than all of its words.
+    #[serde(default)]
+    pub code: Option<String>,
+    pub alphabet: Alphabet,
+    pub words: WordList,
+}
+
+impl Language {
+    /// Makes a language out of its words. Any with letters that aren't in the alphabet are left out.
+    pub fn new(name: impl Into<String>, alphabet: Alphabet, words: &WordList) -> Self {
+        Self {
+            name: name.into(),
+            code: None,
+            words: words.made_of(&alphabet.letters),
+            alphabet,
+        }
+    }
+
+    /// Makes a language out of a list of words, one per line. See [`WordList::parse`].
+    pub fn from_words(name: impl Into<String>, alphabet: Alphabet, txt: &str) -> Self {
+        Self::new(name, alphabet, &WordList::parse(txt))
+    }
+
+    /// Loads a list of words, one per line, from a file.
+    pub fn load<P: AsRef<std::path::Path>>(
+        name: impl Into<String>,
+        alphabet: Alphabet,
+        path: P,
+    ) -> std::io::Result<Self> {
+        Ok(Self::new(name, alphabet, &WordList::load(path)?))
+    }
+
+    pub fn english() -> Self {
+        Self::new("English", Alphabet::english(), &WordList::embedded()).with_code("en")
+    }
+
+    pub fn spanish() -> Self {
+        Self::from_words(
+            "Español",
+            Alphabet::spanish(),
+            include_str!("../../assets/words/es.txt"),
+        )
+        .with_code("es")
+    }
+
+    pub fn german() -> Self {
+        Self::from_words("Deutsch", Alphabet::german(), include_str!("../../assets/words/de.txt")).with_code("de")
+    }
+
+    pub fn french() -> Self {
+        Self::from_words(
+            "Français",
+            Alphabet::french(),
+            include_str!("../../assets/words/fr.txt"),
+        )
+        .with_code("fr")
+    }
+
+    /// Gets one of the built in languages by its code, like `en` or `de`.
+    pub fn builtin(code: &str) -> Option<Self> {
+        match code {
+            "en" => Some(Self::english()),
+            "es" => Some(Self::spanish()),
+            "de" => Some(Self::german()),
+            "fr" => Some(Self::french()),
+            _ => None,
+        }
+    }
+
+    fn with_code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_lists_fit_their_alphabets() {
+        for code in ["en", "es", "de", "fr"] {
+            let language = Language::builtin(code).unwrap();
+            assert_eq!(language.code.as_deref(), Some(code));
+
+            assert!(language.words.len() > 100, "{}", language.name);
+            assert!(language
+                .words
+                .iter()
+                .all(|word| word.chars().all(|chr| language.alphabet.contains(chr))));
+        }
+
+        assert!(Language::spanish().words.iter().any(|v| v.contains('ñ')));
+        assert!(Language::german().words.iter().any(|v| v.contains('ß')));
+        assert!(Language::french().words.iter().any(|v| v.contains('é')));
+    }
+
+    #[test]
+    fn from_words() {
+        let language = Language::from_words("test", Alphabet::german(), "Straße\n\n  grün \nstraße\nçava\n");
+
+        assert_eq!(language.words.iter().collect::<Vec<_>>(), ["straße", "grün"]);
+    }
+}
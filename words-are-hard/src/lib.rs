@@ -5,12 +5,45 @@
 pub mod gen;
 
 mod challenge_instruction;
+mod clock;
+mod daily;
+mod difficulty;
 mod game;
+mod generator_config;
+mod hint;
+mod language;
+mod mode;
+mod save;
+mod score;
 pub use challenge_instruction::{deserialize_challenges, load_challenges, ChallengeInstruction};
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use daily::Date;
+pub use difficulty::{difficulty, difficulty_in, pack_difficulty};
 pub use game::*;
+pub use generator_config::{Exclusions, GeneratorConfig, RuleWeights};
+pub use hint::Hint;
+pub use language::{Alphabet, Language};
+pub use mode::{Endless, GameMode, GameState, Overflow};
+pub use save::{LoadError, SAVE_VERSION};
+pub use score::{RoundScore, ScoreFormula, Scorecard};
+
+mod occurrence;
+pub use occurrence::Occurrence;
 
 mod rule;
 pub use rule::*;
 
+mod target;
+pub use target::Target;
+
+mod solve;
+pub use solve::{apply_all, solve, solve_in, solve_with_limit, Solutions, MAX_CANDIDATES, MAX_SOLVE_LEN};
+
+mod trace;
+pub use trace::{trace, Effect, Trace, TraceStep, TracedChar};
+
+mod when;
+pub use when::{Context, When};
+
 mod words;
-pub use words::{LOWERCASE_CHARS, WORDS};
+pub use words::{WordList, LOWERCASE_CHARS};
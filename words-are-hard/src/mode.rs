@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The rules of play on top of the puzzles themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Guess as many times as you like.
+    #[default]
+    Classic,
+    /// This many wrong guesses for the whole game, and then it's lost.
+    Lives(usize),
+    /// This many wrong guesses for each round, and then the game's lost.
+    LivesPerRound(usize),
+    /// Each round has to be solved within this long. Rounds that run out of time are failed, and the game moves on.
+    TimeAttack(Duration),
+    /// Rounds keep on coming, each made when the last is solved. See [`Game::endless`](crate::Game::endless).
+    Endless(Endless),
+}
+
+/// How an endless game grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Endless {
+    /// The most rules a round can have.
+    pub max_rules: usize,
+    /// What happens once a round has that many.
+    pub overflow: Overflow,
+}
+
+impl Default for Endless {
+    fn default() -> Self {
+        Self {
+            max_rules: 8,
+            overflow: Overflow::default(),
+        }
+    }
+}
+
+/// What an endless game does once its rounds have as many rules as they can.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Overflow {
+    /// Stop adding rules, and keep on making new words for the ones there are.
+    #[default]
+    Stack,
+    /// Drop the oldest rule for each new one.
+    Rotate,
+}
+
+/// Where a game is at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameState {
+    InProgress,
+    /// Every round was solved.
+    Won,
+    /// The game ran out of lives, or every round was played but some were failed.
+    Lost,
+}
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::Target;
+
+/// Which of the chars matching a rule's target the rule acts on, counting from the start of the word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum Occurrence {
+    /// Every one of them.
+    #[default]
+    All,
+    First,
+    Last,
+    /// The nth one, counting from 0, so `Nth(0)` is the same as `First`.
+    Nth(usize),
+    /// The first one, the third one, the fifth one...
+    EveryOther,
+}
+
+impl Occurrence {
+    pub fn is_all(&self) -> bool {
+        *self == Occurrence::All
+    }
+
+    /// Checks if the `index`th match out of `count` matches gets acted on.
+    pub fn selects(self, index: usize, count: usize) -> bool {
+        match self {
+            Occurrence::All => true,
+            Occurrence::First => index == 0,
+            Occurrence::Last => index + 1 == count,
+            Occurrence::Nth(n) => index == n,
+            Occurrence::EveryOther => index.is_multiple_of(2),
+        }
+    }
+
+    /// Works out which chars of the input get acted on, one bool for each char.
+    pub fn select(self, target: &Target, input: &[char]) -> Vec<bool> {
+        let candidates: Vec<bool> = input.iter().map(|chr| target.matches(*chr)).collect();
+
+        self.pick(&candidates)
+    }
+
+    /// Picks from the chars which could be acted on, one bool for each char.
+    pub fn pick(self, candidates: &[bool]) -> Vec<bool> {
+        let count = candidates.iter().filter(|v| **v).count();
+
+        let mut index = 0;
+        candidates
+            .iter()
+            .map(|candidate| {
+                if *candidate == false {
+                    return false;
+                }
+
+                index += 1;
+                self.selects(index - 1, count)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Occurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Occurrence::All => write!(f, "all of them"),
+            Occurrence::First => write!(f, "first only"),
+            Occurrence::Last => write!(f, "last only"),
+            Occurrence::Nth(n) => write!(f, "{} only", ordinal(n + 1)),
+            Occurrence::EveryOther => write!(f, "every other one"),
+        }
+    }
+}
+
+/// Writes a number the way you'd say it in a list, like `2nd` or `13th`.
+pub(crate) fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", n, suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select() {
+        let input: Vec<char> = "banana".chars().collect();
+        let target = Target::Char('a');
+
+        assert_eq!(
+            Occurrence::First.select(&target, &input),
+            [false, true, false, false, false, false]
+        );
+        assert_eq!(
+            Occurrence::Last.select(&target, &input),
+            [false, false, false, false, false, true]
+        );
+        assert_eq!(
+            Occurrence::Nth(1).select(&target, &input),
+            [false, false, false, true, false, false]
+        );
+        assert_eq!(
+            Occurrence::EveryOther.select(&target, &input),
+            [false, true, false, false, false, true]
+        );
+        assert_eq!(
+            Occurrence::All.select(&target, &input),
+            [false, true, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(22), "22nd");
+    }
+}
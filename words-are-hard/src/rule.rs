@@ -1,155 +1,971 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+use crate::{occurrence::ordinal, Context, Occurrence, Solutions, Target, When, LOWERCASE_CHARS, MAX_CANDIDATES};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Rule {
     Convert(TargetDestination),
     Duplicate(Duplicate),
     Remove(Remove),
     Switch(TargetDestination),
+    Reverse(Reverse),
+    Shift(Shift),
+    Swap(Swap),
+    When(When),
+    Insert(Insert),
 }
 
 impl Rule {
-    pub fn convert(target: char, destination: char) -> Rule {
+    pub fn convert(target: impl Into<Target>, destination: char) -> Rule {
         Rule::Convert(TargetDestination::new(target, destination))
     }
 
-    pub fn duplicate(target: char, count: usize) -> Self {
+    pub fn duplicate(target: impl Into<Target>, count: usize) -> Self {
         Rule::Duplicate(Duplicate::new(target, count))
     }
 
-    pub fn remove(target: char) -> Self {
-        Self::Remove(Remove(target))
+    pub fn remove(target: impl Into<Target>) -> Self {
+        Self::Remove(Remove::new(target))
     }
 
-    pub fn switch(target: char, destination: char) -> Rule {
+    pub fn switch(target: impl Into<Target>, destination: char) -> Rule {
         Rule::Switch(TargetDestination::new(target, destination))
     }
 
+    pub fn reverse() -> Self {
+        Rule::Reverse(Reverse { between: None })
+    }
+
+    pub fn reverse_between(target: char) -> Self {
+        Rule::Reverse(Reverse { between: Some(target) })
+    }
+
+    pub fn shift(class: LetterClass, amount: usize) -> Self {
+        Rule::Shift(Shift { class, amount })
+    }
+
+    /// Swaps the chars at two positions, counting from 0.
+    pub fn swap(first: usize, second: usize) -> Self {
+        Rule::Swap(Swap { first, second })
+    }
+
+    pub fn insert(target: impl Into<Target>, letter: char, side: Side) -> Self {
+        Rule::Insert(Insert::new(target, letter, side))
+    }
+
+    /// Makes the rule only act where the context fits.
+    pub fn when(self, context: Context) -> Self {
+        Rule::When(When {
+            rule: Box::new(self),
+            context,
+        })
+    }
+
+    /// Makes the rule act only on some of the chars matching its target. Rules without an [`Occurrence`] are
+    /// returned as they are.
+    pub fn with_occurrence(mut self, occurrence: Occurrence) -> Self {
+        self.set_occurrence(occurrence);
+
+        self
+    }
+
+    fn set_occurrence(&mut self, occurrence: Occurrence) {
+        match self {
+            Rule::Convert(td) | Rule::Switch(td) => td.occurrence = occurrence,
+            Rule::Duplicate(dup) => dup.occurrence = occurrence,
+            Rule::Remove(rmv) => rmv.occurrence = occurrence,
+            Rule::Insert(ins) => ins.occurrence = occurrence,
+            Rule::When(when) => when.rule.set_occurrence(occurrence),
+            Rule::Reverse(_) | Rule::Shift(_) | Rule::Swap(_) => {}
+        }
+    }
+
+    /// Gets which of the chars matching the target the rule acts on. Rules without a target act on everything.
+    pub fn occurrence(&self) -> Occurrence {
+        match self {
+            Rule::Convert(td) | Rule::Switch(td) => td.occurrence,
+            Rule::Duplicate(dup) => dup.occurrence,
+            Rule::Remove(rmv) => rmv.occurrence,
+            Rule::Insert(ins) => ins.occurrence,
+            Rule::When(when) => when.rule.occurrence(),
+            Rule::Reverse(_) | Rule::Shift(_) | Rule::Swap(_) => Occurrence::All,
+        }
+    }
+
+    /// Gets the rule inside any `When`s.
+    pub fn innermost(&self) -> &Rule {
+        match self {
+            Rule::When(when) => when.rule.innermost(),
+            rule => rule,
+        }
+    }
+
     /// Applies the rule to the input, returning the output string.
-    pub fn apply(self, input: &str) -> String {
+    pub fn apply(&self, input: &str) -> String {
+        let input: Vec<char> = input.chars().collect();
+
+        self.apply_selected(&input, &self.selection(&input))
+    }
+
+    /// Works out which chars of the input the rule could act on, before its occurrence picks from them.
+    pub(crate) fn candidates(&self, input: &[char]) -> Vec<bool> {
+        let matching = |target: &Target| input.iter().map(|chr| target.matches(*chr)).collect();
+
+        match self {
+            Rule::Convert(td) | Rule::Switch(td) => matching(&td.target),
+            Rule::Duplicate(dup) => matching(&dup.target),
+            Rule::Remove(rmv) => matching(&rmv.target),
+            Rule::Insert(ins) => matching(&ins.target),
+            Rule::Shift(shift) => input.iter().map(|chr| shift.class.contains(*chr)).collect(),
+            // these move the whole word around rather than acting on chars
+            Rule::Reverse(_) | Rule::Swap(_) => vec![false; input.len()],
+            Rule::When(when) => when.candidates(input),
+        }
+    }
+
+    /// Works out which chars of the input the rule acts on.
+    pub(crate) fn selection(&self, input: &[char]) -> Vec<bool> {
+        self.occurrence().pick(&self.candidates(input))
+    }
+
+    /// Applies the rule to the chars picked out by `selected`, rather than working them out from its target.
+    fn apply_selected(&self, input: &[char], selected: &[bool]) -> String {
         match self {
-            Rule::Convert(cnv_data) => input.replace(cnv_data.target, &format!("{}", cnv_data.destination)),
-            Rule::Duplicate(dep) => dep.apply(input),
-            Rule::Remove(rmv) => rmv.apply(input),
-            Rule::Switch(target_destination) => switcher(&target_destination, input),
+            Rule::Convert(cnv_data) => input
+                .iter()
+                .zip(selected)
+                .map(|(chr, selected)| if *selected { cnv_data.destination } else { *chr })
+                .collect(),
+            Rule::Duplicate(dep) => dep.apply_selected(input, selected),
+            Rule::Remove(rmv) => rmv.apply_selected(input, selected),
+            Rule::Insert(ins) => ins.apply_selected(input, selected),
+            Rule::Switch(target_destination) => switcher(target_destination, input, selected),
+            Rule::Reverse(rev) => rev.sources(input).into_iter().map(|i| input[i]).collect(),
+            Rule::Shift(shift) => input
+                .iter()
+                .zip(selected)
+                .map(|(chr, selected)| if *selected { shift.shift(*chr) } else { *chr })
+                .collect(),
+            Rule::Swap(swap) => swap.sources(input.len()).into_iter().map(|i| input[i]).collect(),
+            Rule::When(when) => when.rule.apply_selected(input, selected),
         }
     }
 
-    /// Gets the target of the given rule.
-    pub fn target(self) -> char {
+    /// Gets the target of the given rule, if it has one. Reversing the whole word, shifting and swapping don't.
+    pub fn target(&self) -> Option<Target> {
         match self {
-            Rule::Convert(cnv) => cnv.target,
-            Rule::Duplicate(dup) => dup.target,
-            Rule::Remove(rmv) => rmv.0,
-            Rule::Switch(td) => td.target,
+            Rule::Convert(cnv) => Some(cnv.target.clone()),
+            Rule::Duplicate(dup) => Some(dup.target.clone()),
+            Rule::Remove(rmv) => Some(rmv.target.clone()),
+            Rule::Insert(ins) => Some(ins.target.clone()),
+            Rule::Switch(td) => Some(td.target.clone()),
+            Rule::Reverse(rev) => rev.between.map(Target::Char),
+            Rule::Shift(_) | Rule::Swap(_) => None,
+            Rule::When(when) => when.rule.target(),
+        }
+    }
+
+    /// Gets the kind of the given rule. A `When` is the kind of the rule inside it.
+    pub fn kind(&self) -> RuleKind {
+        match self {
+            Rule::Convert(_) => RuleKind::Convert,
+            Rule::Duplicate(_) => RuleKind::Duplicate,
+            Rule::Remove(_) => RuleKind::Remove,
+            Rule::Insert(_) => RuleKind::Insert,
+            Rule::Switch(_) => RuleKind::Switch,
+            Rule::Reverse(_) => RuleKind::Reverse,
+            Rule::Shift(_) => RuleKind::Shift,
+            Rule::Swap(_) => RuleKind::Swap,
+            Rule::When(when) => when.rule.kind(),
+        }
+    }
+
+    /// The most chars the rule can turn a word of `len` chars into.
+    pub(crate) fn max_output_len(&self, len: usize) -> usize {
+        match self.innermost() {
+            Rule::Duplicate(dup) => len.saturating_mul(dup.count.max(1)),
+            Rule::Insert(_) => len.saturating_mul(2),
+            _ => len,
+        }
+    }
+
+    /// Returns every input which this rule turns into `output`, sorted and without duplicates.
+    ///
+    /// `Remove` can be undone by any number of target chars, so inputs longer than `max_len` chars are never
+    /// returned. Targeting a set or a class of chars multiplies the number of inputs, since any of them could have
+    /// been there, so past [`MAX_CANDIDATES`] the rest are given up on and the solutions are marked as truncated.
+    pub fn invert(&self, output: &str, max_len: usize) -> Solutions {
+        let output: Vec<char> = output.chars().collect();
+
+        // rules which only act on some of their targets can't be undone exactly, so we guess and check instead
+        let check = matches!(self, Rule::When(_)) || self.occurrence().is_all() == false;
+        let mut truncated = false;
+        let inputs = self.invert_chars(&output, max_len, check.then_some(self), &mut truncated);
+
+        let inputs: std::collections::BTreeSet<String> = inputs
+            .into_iter()
+            .filter(|v| v.len() <= max_len)
+            .map(|v| v.into_iter().collect())
+            .collect();
+
+        Solutions {
+            words: inputs.into_iter().collect(),
+            truncated,
+        }
+    }
+
+    /// If `check` is set, this returns every input which could have been the output's, and only the ones which
+    /// `check` really does turn into the output are kept. `truncated` gets set if any were given up on.
+    fn invert_chars(
+        &self,
+        output: &[char],
+        max_len: usize,
+        check: Option<&Rule>,
+        truncated: &mut bool,
+    ) -> Vec<Vec<char>> {
+        match self {
+            Rule::Convert(cnv) => invert_convert(cnv, output, check, truncated),
+            Rule::Duplicate(dup) => dup.invert(output, max_len, check, truncated),
+            Rule::Remove(rmv) => rmv.invert(output, max_len, check, truncated),
+            Rule::Insert(ins) => ins.invert(output, check, truncated),
+            Rule::Switch(td) => invert_switcher(td, output, check, truncated),
+            // reversing twice puts everything back, and the `between` chars never move
+            Rule::Reverse(rev) => vec![rev.sources(output).into_iter().map(|i| output[i]).collect()],
+            Rule::Shift(shift) => match check {
+                None => vec![output.iter().map(|chr| shift.unshift(*chr)).collect()],
+                // any letter might have been shifted or not
+                Some(check) => {
+                    let inputs = output.iter().fold(vec![vec![]], |inputs, &chr| {
+                        let mut options = vec![chr];
+                        if shift.class.contains(chr) && shift.unshift(chr) != chr {
+                            options.push(shift.unshift(chr));
+                        }
+
+                        let inputs = inputs.into_iter().flat_map(|input: Vec<char>| {
+                            options.iter().map(move |option| {
+                                let mut input = input.clone();
+                                input.push(*option);
+                                input
+                            })
+                        });
+                        capped(inputs, truncated)
+                    });
+
+                    applies_to(check, inputs, output)
+                }
+            },
+            // so does swapping twice
+            Rule::Swap(swap) => vec![swap.sources(output.len()).into_iter().map(|i| output[i]).collect()],
+            Rule::When(when) => when.rule.invert_chars(output, max_len, check, truncated),
         }
     }
 }
 
+/// The different kinds of [`Rule`], without any of their data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum RuleKind {
+    Convert,
+    Duplicate,
+    Remove,
+    Switch,
+    Reverse,
+    Shift,
+    Swap,
+    Insert,
+}
+
 impl fmt::Display for Rule {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Rule::Convert(cnv) => write!(f, "Convert {} to {}", cnv.target, cnv.destination),
-            Rule::Duplicate(dep) => write!(f, "Duplicate {} {} times", dep.target, dep.count),
-            Rule::Remove(rm) => write!(f, "Delete {}", rm.0),
-            Rule::Switch(td) => write!(f, "{} switches position with the next {}", td.target, td.destination),
+            Rule::Convert(cnv) => write!(f, "Convert {} to {}", cnv.target, cnv.destination)?,
+            Rule::Duplicate(dep) => write!(f, "Duplicate {} {} times", dep.target, dep.count)?,
+            Rule::Remove(rm) => write!(f, "Delete {}", rm.target)?,
+            Rule::Insert(ins) => write!(f, "Insert {} {} {}", ins.letter, ins.side, ins.target)?,
+            Rule::Switch(td) => write!(f, "{} switches position with the next {}", td.target, td.destination)?,
+            Rule::Reverse(Reverse { between: None }) => write!(f, "Reverse the word")?,
+            Rule::Reverse(Reverse { between: Some(target) }) => {
+                write!(f, "Reverse everything between each pair of {}", target)?
+            }
+            Rule::Shift(shift) => write!(f, "Shift every {} {} along", shift.class, shift.amount)?,
+            Rule::Swap(swap) => write!(
+                f,
+                "Swap the {} and {} letters",
+                ordinal(swap.first + 1),
+                ordinal(swap.second + 1)
+            )?,
+            // the rule inside has already said which occurrences it acts on
+            Rule::When(when) => return write!(f, "{}, only {}", when.rule, when.context),
+        }
+
+        if self.occurrence().is_all() == false {
+            write!(f, " ({})", self.occurrence())?;
         }
+
+        Ok(())
     }
 }
 
-fn switcher(td: &TargetDestination, input: &str) -> String {
-    let mut output_string = String::new();
-
-    let mut data_vec = vec![];
+/// Switches the chars picked out by `selected`. Targets which aren't selected are just like any other char.
+fn switcher(td: &TargetDestination, input: &[char], selected: &[bool]) -> String {
+    switch_sources(td, input, selected)
+        .into_iter()
+        .map(|i| input[i])
+        .collect()
+}
 
-    for (byte_idx, char) in input.char_indices() {
-        if char == td.target {
-            // put this at the front...we might not be hte most important...
-            data_vec.insert(0, byte_idx);
+/// Works out where each char of the switched word comes from. Each target pairs up with the next destination
+/// which isn't already taken by an earlier target, and the two trade places.
+pub(crate) fn switch_sources(td: &TargetDestination, input: &[char], selected: &[bool]) -> Vec<usize> {
+    let mut sources: Vec<usize> = (0..input.len()).collect();
+    let mut waiting = std::collections::VecDeque::new();
 
-            // and put this signum...
-            output_string.push('Q');
-        } else if char == td.destination {
-            if let Some(old_byte_idx) = data_vec.pop() {
-                // replace 'Q' with another char. Because we are always ascii, this works!
-                unsafe {
-                    output_string.as_bytes_mut()[old_byte_idx] = td.destination as u8;
-                }
-                output_string.push(td.target);
-            } else {
-                output_string.push(td.destination);
+    for (i, chr) in input.iter().enumerate() {
+        if selected[i] {
+            waiting.push_back(i);
+        } else if *chr == td.destination {
+            if let Some(target_idx) = waiting.pop_front() {
+                sources.swap(target_idx, i);
             }
+        }
+    }
+
+    sources
+}
+
+/// Keeps the inputs which the rule really does turn into the output.
+fn applies_to(rule: &Rule, inputs: Vec<Vec<char>>, output: &[char]) -> Vec<Vec<char>> {
+    let output: String = output.iter().collect();
+
+    inputs
+        .into_iter()
+        .filter(|input| rule.apply(&input.iter().collect::<String>()) == output)
+        .collect()
+}
+
+/// Collects up to [`MAX_CANDIDATES`] inputs, setting `truncated` if there were more.
+fn capped(inputs: impl Iterator<Item = Vec<char>>, truncated: &mut bool) -> Vec<Vec<char>> {
+    let mut inputs: Vec<_> = inputs.take(MAX_CANDIDATES + 1).collect();
+    if inputs.len() > MAX_CANDIDATES {
+        inputs.truncate(MAX_CANDIDATES);
+        *truncated = true;
+    }
+
+    inputs
+}
+
+/// Every `destination` could have been any target or a `destination` before, so this is every mix of them.
+fn invert_convert(
+    td: &TargetDestination,
+    output: &[char],
+    check: Option<&Rule>,
+    truncated: &mut bool,
+) -> Vec<Vec<char>> {
+    // a target can't survive converting all of them
+    if check.is_none()
+        && output
+            .iter()
+            .any(|chr| *chr != td.destination && td.target.matches(*chr))
+    {
+        return vec![];
+    }
+
+    let mut options = td.target.chars();
+    if options.contains(&td.destination) == false {
+        options.push(td.destination);
+    }
+
+    let inputs = output.iter().fold(vec![vec![]], |inputs, &chr| {
+        if chr == td.destination {
+            let inputs = inputs.into_iter().flat_map(|input| {
+                options.iter().map(move |option| {
+                    let mut input = input.clone();
+                    input.push(*option);
+                    input
+                })
+            });
+            capped(inputs, truncated)
         } else {
-            output_string.push(char);
+            inputs
+                .into_iter()
+                .map(|mut input| {
+                    input.push(chr);
+                    input
+                })
+                .collect()
         }
+    });
+
+    // when only some of the targets get converted, the rest might not have been
+    match check {
+        Some(check) => applies_to(check, inputs, output),
+        None => inputs,
+    }
+}
+
+/// Switching only shuffles the targets and destinations around, so we try every arrangement of them in the slots
+/// they occupy and keep the ones which switch back into the output, giving up after [`MAX_CANDIDATES`] of them.
+fn invert_switcher(
+    td: &TargetDestination,
+    output: &[char],
+    check: Option<&Rule>,
+    truncated: &mut bool,
+) -> Vec<Vec<char>> {
+    // a destination which is also a target never gets switched
+    if td.target.matches(td.destination) && check.is_none() {
+        return vec![output.to_vec()];
     }
 
-    for orphan in data_vec {
-        // replace 'Q' with another char. Because we are always ascii, this works!
-        unsafe {
-            output_string.as_bytes_mut()[orphan] = td.target as u8;
+    let rule = match check {
+        Some(check) => check.clone(),
+        None => Rule::Switch(td.clone()),
+    };
+
+    let slots: Vec<usize> = output
+        .iter()
+        .enumerate()
+        .filter(|(_, chr)| td.target.matches(**chr) || **chr == td.destination)
+        .map(|(i, _)| i)
+        .collect();
+    let mut arrangement: Vec<char> = slots.iter().map(|i| output[*i]).collect();
+    arrangement.sort_unstable();
+    let output_string: String = output.iter().collect();
+
+    let mut inputs = vec![];
+    for tried in 1.. {
+        let mut input = output.to_vec();
+        for (slot, chr) in slots.iter().zip(arrangement.iter()) {
+            input[*slot] = *chr;
+        }
+
+        let input_string: String = input.iter().collect();
+        if rule.apply(&input_string) == output_string {
+            inputs.push(input);
+        }
+
+        if next_permutation(&mut arrangement) == false {
+            break;
+        }
+        if tried == MAX_CANDIDATES {
+            *truncated = true;
+            break;
         }
     }
 
-    assert!(output_string.contains('Q') == false);
+    inputs
+}
+
+/// Rearranges the chars into the next lexicographic permutation, returning false once they're back to the first.
+/// Repeated chars don't make repeated permutations.
+fn next_permutation(chars: &mut [char]) -> bool {
+    let pivot = match chars.windows(2).rposition(|w| w[0] < w[1]) {
+        Some(pivot) => pivot,
+        None => {
+            chars.reverse();
+            return false;
+        }
+    };
+
+    let successor = chars.iter().rposition(|chr| *chr > chars[pivot]).unwrap();
+    chars.swap(pivot, successor);
+    chars[pivot + 1..].reverse();
 
-    output_string
+    true
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
 pub struct TargetDestination {
-    pub target: char,
+    pub target: Target,
     pub destination: char,
+    #[serde(default, skip_serializing_if = "Occurrence::is_all")]
+    pub occurrence: Occurrence,
 }
 
 impl TargetDestination {
-    pub fn new(target: char, destination: char) -> Self {
-        Self { target, destination }
+    pub fn new(target: impl Into<Target>, destination: char) -> Self {
+        Self {
+            target: target.into(),
+            destination,
+            occurrence: Occurrence::All,
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
 pub struct Duplicate {
-    pub target: char,
+    pub target: Target,
     pub count: usize,
+    #[serde(default, skip_serializing_if = "Occurrence::is_all")]
+    pub occurrence: Occurrence,
 }
 
 impl Duplicate {
-    pub fn new(target: char, count: usize) -> Self {
-        Self { target, count }
+    pub fn new(target: impl Into<Target>, count: usize) -> Self {
+        Self {
+            target: target.into(),
+            count,
+            occurrence: Occurrence::All,
+        }
     }
 }
 
 impl Duplicate {
-    pub fn apply(self, input: &str) -> String {
-        input.chars().fold(String::new(), |mut output, chr| {
-            let amount = if chr == self.target { self.count } else { 1 };
+    pub fn apply(&self, input: &str) -> String {
+        let input: Vec<char> = input.chars().collect();
+
+        self.apply_selected(&input, &self.occurrence.select(&self.target, &input))
+    }
+
+    fn apply_selected(&self, input: &[char], selected: &[bool]) -> String {
+        input
+            .iter()
+            .zip(selected)
+            .fold(String::new(), |mut output, (&chr, &selected)| {
+                let amount = if selected { self.count } else { 1 };
+
+                for _ in 0..amount {
+                    output.push(chr);
+                }
+
+                output
+            })
+    }
 
-            for _ in 0..amount {
-                output.push(chr);
+    /// Every run of a target in the output must be a multiple of `count`, which we shrink back down.
+    fn invert(&self, output: &[char], max_len: usize, check: Option<&Rule>, truncated: &mut bool) -> Vec<Vec<char>> {
+        match self.count {
+            0 => {
+                let remove = Remove {
+                    target: self.target.clone(),
+                    occurrence: self.occurrence,
+                };
+                return remove.invert(output, max_len, check, truncated);
             }
+            1 => return vec![output.to_vec()],
+            _ => {}
+        }
 
-            output
-        })
+        // when only some targets are duplicated, any target could be a single one or the start of a duplicated one
+        if let Some(check) = check {
+            let mut inputs = vec![];
+            self.unduplicate(output, &mut vec![], &mut inputs, truncated);
+
+            return applies_to(check, inputs, output);
+        }
+
+        let mut input = vec![];
+        for run in output.chunk_by(|a, b| a == b) {
+            if self.target.matches(run[0]) {
+                if run.len() % self.count != 0 {
+                    return vec![];
+                }
+                input.extend_from_slice(&run[..run.len() / self.count]);
+            } else {
+                input.extend_from_slice(run);
+            }
+        }
+
+        vec![input]
+    }
+
+    fn unduplicate(&self, output: &[char], input: &mut Vec<char>, inputs: &mut Vec<Vec<char>>, truncated: &mut bool) {
+        if inputs.len() >= MAX_CANDIDATES {
+            *truncated = true;
+            return;
+        }
+
+        let Some((chr, rest)) = output.split_first() else {
+            inputs.push(input.clone());
+            return;
+        };
+
+        input.push(*chr);
+
+        // either the char was left alone...
+        self.unduplicate(rest, input, inputs, truncated);
+
+        // ...or it was duplicated
+        if self.target.matches(*chr) && output.len() >= self.count && output[..self.count].iter().all(|v| v == chr) {
+            self.unduplicate(&output[self.count..], input, inputs, truncated);
+        }
+
+        input.pop();
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
-pub struct Remove(pub char);
+/// Deletes chars matching the target.
+///
+/// In JSON, a `Remove` which deletes every match is stored as a bare target, like it was before there were
+/// occurrences.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(from = "RemoveRepr", into = "RemoveRepr")]
+pub struct Remove {
+    pub target: Target,
+    pub occurrence: Occurrence,
+}
 
 impl Remove {
+    pub fn new(target: impl Into<Target>) -> Self {
+        Self {
+            target: target.into(),
+            occurrence: Occurrence::All,
+        }
+    }
+
+    pub fn apply(&self, input: &str) -> String {
+        let input: Vec<char> = input.chars().collect();
+
+        self.apply_selected(&input, &self.occurrence.select(&self.target, &input))
+    }
+
+    fn apply_selected(&self, input: &[char], selected: &[bool]) -> String {
+        input
+            .iter()
+            .zip(selected)
+            .fold(String::new(), |mut output, (&chr, &selected)| {
+                if selected {
+                    // do nothing, since this will effectively remove
+                    // the target char
+                } else {
+                    output.push(chr);
+                }
+
+                output
+            })
+    }
+
+    /// The removed chars could have been anywhere, so we sprinkle up to `max_len` worth of them into every gap,
+    /// fewest first.
+    fn invert(&self, output: &[char], max_len: usize, check: Option<&Rule>, truncated: &mut bool) -> Vec<Vec<char>> {
+        if check.is_none() && output.iter().any(|chr| self.target.matches(*chr)) {
+            return vec![];
+        }
+
+        let mut inputs = vec![];
+        for extra in 0..=max_len.saturating_sub(output.len()) {
+            sprinkle(&self.target.chars(), output, extra, &mut vec![], &mut inputs, truncated);
+        }
+
+        match check {
+            Some(check) => applies_to(check, inputs, output),
+            None => inputs,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RemoveRepr {
+    Target(Target),
+    Full {
+        target: Target,
+        #[serde(default)]
+        occurrence: Occurrence,
+    },
+}
+
+impl From<RemoveRepr> for Remove {
+    fn from(repr: RemoveRepr) -> Self {
+        match repr {
+            RemoveRepr::Target(target) => Remove::new(target),
+            RemoveRepr::Full { target, occurrence } => Remove { target, occurrence },
+        }
+    }
+}
+
+impl From<Remove> for RemoveRepr {
+    fn from(remove: Remove) -> Self {
+        if remove.occurrence.is_all() {
+            RemoveRepr::Target(remove.target)
+        } else {
+            RemoveRepr::Full {
+                target: remove.target,
+                occurrence: remove.occurrence,
+            }
+        }
+    }
+}
+
+/// Which side of its target an [`Insert`] puts its letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum Side {
+    Before,
+    #[default]
+    After,
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Side::Before => write!(f, "before"),
+            Side::After => write!(f, "after"),
+        }
+    }
+}
+
+/// Puts a letter next to every target, like putting an x after every a.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct Insert {
+    pub target: Target,
+    pub letter: char,
+    pub side: Side,
+    #[serde(default, skip_serializing_if = "Occurrence::is_all")]
+    pub occurrence: Occurrence,
+}
+
+impl Insert {
+    pub fn new(target: impl Into<Target>, letter: char, side: Side) -> Self {
+        Self {
+            target: target.into(),
+            letter,
+            side,
+            occurrence: Occurrence::All,
+        }
+    }
+
+    pub fn apply(&self, input: &str) -> String {
+        let input: Vec<char> = input.chars().collect();
+
+        self.apply_selected(&input, &self.occurrence.select(&self.target, &input))
+    }
+
+    fn apply_selected(&self, input: &[char], selected: &[bool]) -> String {
+        let mut output = String::new();
+        for (&chr, &selected) in input.iter().zip(selected) {
+            if selected && self.side == Side::Before {
+                output.push(self.letter);
+            }
+            output.push(chr);
+            if selected && self.side == Side::After {
+                output.push(self.letter);
+            }
+        }
+
+        output
+    }
+
+    /// Every target must have the letter next to it, which we take back out.
+    fn invert(&self, output: &[char], check: Option<&Rule>, truncated: &mut bool) -> Vec<Vec<char>> {
+        if let Some(check) = check {
+            // when only some targets get a letter, any of the letters next to a target might have been inserted
+            let inserted: Vec<usize> = output
+                .iter()
+                .enumerate()
+                .filter(|(i, chr)| {
+                    let next_to = match self.side {
+                        Side::Before => output.get(i + 1),
+                        Side::After => i.checked_sub(1).map(|i| &output[i]),
+                    };
+                    **chr == self.letter && next_to.is_some_and(|chr| self.target.matches(*chr))
+                })
+                .map(|(i, _)| i)
+                .collect();
+
+            let masks = 1usize.checked_shl(inserted.len() as u32).unwrap_or(usize::MAX);
+            *truncated |= masks > MAX_CANDIDATES;
+            let inputs = (0..masks.min(MAX_CANDIDATES))
+                .map(|mask| {
+                    output
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| {
+                            inserted
+                                .iter()
+                                .position(|v| v == i)
+                                .is_none_or(|bit| mask.checked_shr(bit as u32).is_none_or(|mask| mask & 1 == 0))
+                        })
+                        .map(|(_, chr)| *chr)
+                        .collect()
+                })
+                .collect();
+
+            return applies_to(check, inputs, output);
+        }
+
+        // inserting before is inserting after, backwards
+        let mut output = output.to_vec();
+        if self.side == Side::Before {
+            output.reverse();
+        }
+
+        let mut input = Vec::with_capacity(output.len());
+        let mut chars = output.into_iter();
+        while let Some(chr) = chars.next() {
+            input.push(chr);
+            if self.target.matches(chr) && chars.next() != Some(self.letter) {
+                return vec![];
+            }
+        }
+
+        if self.side == Side::Before {
+            input.reverse();
+        }
+
+        vec![input]
+    }
+}
+
+/// Reverses the whole word, or if `between` is set, only the chars between each pair of `between`.
+///
+/// With `between` set, the first and second occurrences make a pair, then the third and fourth, and so on. A last
+/// occurrence without a partner doesn't reverse anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct Reverse {
+    pub between: Option<char>,
+}
+
+impl Reverse {
     pub fn apply(self, input: &str) -> String {
-        input.chars().fold(String::new(), |mut output, chr| {
-            if chr == self.0 {
-                // do nothing, since this will effectively remove
-                // the target char
-            } else {
-                output.push(chr);
+        let input: Vec<char> = input.chars().collect();
+
+        self.sources(&input).into_iter().map(|i| input[i]).collect()
+    }
+
+    /// Works out which index of the input ends up at each index of the output.
+    pub(crate) fn sources(self, input: &[char]) -> Vec<usize> {
+        let mut sources: Vec<usize> = (0..input.len()).collect();
+
+        match self.between {
+            None => sources.reverse(),
+            Some(between) => {
+                let occurrences: Vec<usize> = input
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, chr)| **chr == between)
+                    .map(|(i, _)| i)
+                    .collect();
+
+                for pair in occurrences.chunks_exact(2) {
+                    sources[pair[0] + 1..pair[1]].reverse();
+                }
             }
+        }
 
-            output
-        })
+        sources
+    }
+}
+
+/// Swaps the chars at two positions, counting from 0. Nothing happens if the word is too short for either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub struct Swap {
+    pub first: usize,
+    pub second: usize,
+}
+
+impl Swap {
+    /// Works out which index of the input ends up at each index of the output.
+    pub(crate) fn sources(self, len: usize) -> Vec<usize> {
+        let mut sources: Vec<usize> = (0..len).collect();
+        if self.first < len && self.second < len {
+            sources.swap(self.first, self.second);
+        }
+
+        sources
+    }
+}
+
+/// A group of letters which a [`Shift`] rotates through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LetterClass {
+    Letters,
+    Vowels,
+    Consonants,
+}
+
+impl LetterClass {
+    pub const ALL: [LetterClass; 3] = [LetterClass::Letters, LetterClass::Vowels, LetterClass::Consonants];
+
+    /// The letters in the class, in alphabetical order.
+    pub fn letters(self) -> Vec<char> {
+        LOWERCASE_CHARS.filter(|chr| self.contains(*chr)).collect()
+    }
+
+    pub fn contains(self, chr: char) -> bool {
+        let is_vowel = matches!(chr, 'a' | 'e' | 'i' | 'o' | 'u');
+
+        match self {
+            LetterClass::Letters => chr.is_ascii_lowercase(),
+            LetterClass::Vowels => is_vowel,
+            LetterClass::Consonants => chr.is_ascii_lowercase() && is_vowel == false,
+        }
+    }
+}
+
+impl fmt::Display for LetterClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LetterClass::Letters => write!(f, "letter"),
+            LetterClass::Vowels => write!(f, "vowel"),
+            LetterClass::Consonants => write!(f, "consonant"),
+        }
+    }
+}
+
+/// Moves every letter in the class `amount` letters along, wrapping around at the end of the class. With vowels, an
+/// 'a' shifted by 1 becomes an 'e', and a 'u' becomes an 'a'.
+///
+/// Every letter ends up somewhere different, so unlike a `Convert`, this can always be undone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Shift {
+    pub class: LetterClass,
+    pub amount: usize,
+}
+
+impl Shift {
+    /// Shifts a single char, leaving it alone if it's not in the class.
+    pub fn shift(self, chr: char) -> char {
+        self.rotate(chr, self.amount)
+    }
+
+    /// Undoes [`Shift::shift`].
+    pub fn unshift(self, chr: char) -> char {
+        let len = self.class.letters().len();
+
+        self.rotate(chr, len - self.amount % len)
+    }
+
+    fn rotate(self, chr: char, amount: usize) -> char {
+        let letters = self.class.letters();
+
+        match letters.iter().position(|v| *v == chr) {
+            Some(idx) => letters[(idx + amount) % letters.len()],
+            None => chr,
+        }
+    }
+}
+
+/// Puts exactly `extra` of the options into the gaps around the rest of the chars, in every way there is.
+fn sprinkle(
+    options: &[char],
+    rest: &[char],
+    extra: usize,
+    input: &mut Vec<char>,
+    inputs: &mut Vec<Vec<char>>,
+    truncated: &mut bool,
+) {
+    if inputs.len() >= MAX_CANDIDATES {
+        *truncated = true;
+        return;
+    }
+
+    // either we put another removed char here...
+    if extra > 0 {
+        for chr in options {
+            input.push(*chr);
+            sprinkle(options, rest, extra - 1, input, inputs, truncated);
+            input.pop();
+        }
+    }
+
+    // ...or we move on to the next char we know about
+    match rest.split_first() {
+        Some((next, rest)) => {
+            input.push(*next);
+            sprinkle(options, rest, extra, input, inputs, truncated);
+            input.pop();
+        }
+        None if extra == 0 => inputs.push(input.clone()),
+        None => {}
     }
 }
 
@@ -159,10 +975,7 @@ mod tests {
 
     #[test]
     fn convert() {
-        let ex = Rule::Convert(TargetDestination {
-            target: 'b',
-            destination: 'a',
-        });
+        let ex = Rule::Convert(TargetDestination::new('b', 'a'));
 
         assert_eq!(ex.apply("bob"), "aoa");
         assert_eq!(ex.apply("bbb"), "aaa");
@@ -171,7 +984,7 @@ mod tests {
 
     #[test]
     fn duplicate() {
-        let ex = Duplicate { target: 'b', count: 3 };
+        let ex = Duplicate::new('b', 3);
 
         assert_eq!(ex.apply("bob"), "bbbobbb");
         assert_eq!(ex.apply("bbb"), "bbbbbbbbb");
@@ -180,25 +993,287 @@ mod tests {
 
     #[test]
     fn delete() {
-        let ex = Remove('b');
+        let ex = Remove::new('b');
 
         assert_eq!(ex.apply("bob"), "o");
         assert_eq!(ex.apply("bbb"), "");
         assert_eq!(ex.apply("aoa"), "aoa");
     }
 
+    #[test]
+    fn insert() {
+        let after = Rule::insert('a', 'x', Side::After);
+        assert_eq!(after.apply("banana"), "baxnaxnax");
+        assert_eq!(after.to_string(), "Insert x after a");
+
+        let before = Rule::insert(Target::Vowel, 'h', Side::Before);
+        assert_eq!(before.apply("oat"), "hohat");
+
+        assert_eq!(after.invert("baxnaxnax", 10).words, ["banana"]);
+        assert!(after.invert("baxna", 10).words.is_empty());
+        assert_eq!(before.invert("hohat", 10).words, ["oat"]);
+        assert_eq!(Rule::insert('a', 'a', Side::Before).invert("aaaa", 10).words, ["aa"]);
+
+        // more inserted letters than bits in a mask
+        let first = Rule::insert('a', 'x', Side::After).with_occurrence(Occurrence::First);
+        let long = format!("{}a", "x".repeat(70));
+        assert!(first.invert(&first.apply(&long), 80).words.contains(&long));
+        let first = Rule::insert('a', 'a', Side::After).with_occurrence(Occurrence::First);
+        let long = "a".repeat(70);
+        assert!(first.invert(&first.apply(&long), 80).words.contains(&long));
+    }
+
     #[test]
     fn switch() {
-        let ex = TargetDestination {
-            target: 'a',
-            destination: 'b',
-        };
+        let ex = Rule::Switch(TargetDestination::new('a', 'b'));
 
-        assert_eq!(switcher(&ex, "abba"), "baba");
-        assert_eq!(switcher(&ex, "aobobabrt"), "boaobbart");
-        assert_eq!(switcher(&ex, "bca"), "bca");
+        assert_eq!(ex.apply("abba"), "baba");
+        assert_eq!(ex.apply("aobobabrt"), "boaobbart");
+        assert_eq!(ex.apply("bca"), "bca");
 
         // make sure we don't miss internals
         assert_eq!(Rule::switch('o', 'l').apply("doorbells"), "dllrbeoos");
     }
+
+    #[test]
+    fn switch_any_char() {
+        assert_eq!(Rule::switch('Q', 'u').apply("QuQ"), "uQQ");
+        assert_eq!(Rule::switch('ñ', 'a').apply("niña"), "niañ");
+        assert_eq!(Rule::switch('s', 'ß').apply("süß"), "ßüs");
+        assert_eq!(Rule::switch('é', '😀').apply("é😀é"), "😀éé");
+    }
+
+    /// Switches the slow way: each target, from left to right, swaps with the first destination after it which
+    /// no earlier target has already taken.
+    fn naive_switch(target: char, destination: char, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        let original = chars.clone();
+        let mut taken = vec![false; chars.len()];
+
+        for i in 0..original.len() {
+            if original[i] != target {
+                continue;
+            }
+
+            let partner = (i + 1..original.len()).find(|j| original[*j] == destination && taken[*j] == false);
+            if let Some(j) = partner {
+                taken[j] = true;
+                chars.swap(i, j);
+            }
+        }
+
+        chars.into_iter().collect()
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn switch_matches_naive(
+            input in "[abQñßé😀]{0,12}",
+            target in proptest::char::ranges(vec!['a'..='b', 'Q'..='Q', 'ñ'..='ñ', '😀'..='😀'].into()),
+            destination in proptest::char::ranges(vec!['a'..='b', 'ß'..='ß', 'é'..='é'].into()),
+        ) {
+            proptest::prop_assume!(target != destination);
+
+            let rule = Rule::switch(target, destination);
+            let output = rule.apply(&input);
+
+            proptest::prop_assert_eq!(&output, &naive_switch(target, destination, &input));
+            proptest::prop_assert!(rule.invert(&output, input.chars().count()).words.contains(&input));
+        }
+    }
+
+    #[test]
+    fn targets() {
+        assert_eq!(Rule::remove(Target::Vowel).apply("education"), "dctn");
+        assert_eq!(Rule::duplicate(Target::Consonant, 2).apply("tea"), "ttea");
+        assert_eq!(Rule::convert(Target::Set(vec!['a', 'e']), 'o').apply("team"), "toom");
+        assert_eq!(Rule::convert(Target::Any, 'x').apply("a-b"), "x-x");
+        assert_eq!(Rule::switch(Target::Vowel, 't').apply("oat"), "tao");
+
+        assert_eq!(
+            Rule::convert(Target::Set(vec!['a', 'e']), 'o').invert("tom", 3).words,
+            ["tam", "tem", "tom"]
+        );
+        assert_eq!(Rule::duplicate(Target::Vowel, 2).invert("teeaa", 10).words, ["tea"]);
+        assert!(Rule::duplicate(Target::Vowel, 2).invert("teaa", 10).words.is_empty());
+        assert_eq!(
+            Rule::remove(Target::Set(vec!['a', 'b'])).invert("c", 2).words,
+            ["ac", "bc", "c", "ca", "cb"]
+        );
+        assert!(Rule::remove(Target::Vowel).invert("cat", 5).words.is_empty());
+    }
+
+    #[test]
+    fn occurrences() {
+        let first = Rule::convert('a', 'o').with_occurrence(Occurrence::First);
+        assert_eq!(first.apply("banana"), "bonana");
+        assert_eq!(first.to_string(), "Convert a to o (first only)");
+
+        let nth = Rule::duplicate('a', 2).with_occurrence(Occurrence::Nth(1));
+        assert_eq!(nth.apply("banana"), "banaana");
+        assert_eq!(nth.to_string(), "Duplicate a 2 times (2nd only)");
+
+        assert_eq!(
+            Rule::remove('a').with_occurrence(Occurrence::Last).apply("banana"),
+            "banan"
+        );
+        assert_eq!(
+            Rule::remove('a')
+                .with_occurrence(Occurrence::EveryOther)
+                .apply("banana"),
+            "bnan"
+        );
+        assert_eq!(
+            Rule::switch('n', 'a').with_occurrence(Occurrence::Last).apply("banana"),
+            "banaan"
+        );
+
+        assert_eq!(first.invert("bonana", 10).words, ["banana"]);
+        assert_eq!(first.invert("bonona", 10).words, ["banona", "bonana"]);
+        assert!(first.invert("banana", 10).words.is_empty());
+        assert_eq!(nth.invert("baaaa", 10).words, ["baaa"]);
+        assert_eq!(
+            Rule::remove('a')
+                .with_occurrence(Occurrence::First)
+                .invert("bcab", 5)
+                .words,
+            ["abcab", "bacab", "bcaab"]
+        );
+    }
+
+    #[test]
+    fn swap() {
+        let ex = Rule::swap(1, 4);
+
+        assert_eq!(ex.apply("abcde"), "aecdb");
+        assert_eq!(ex.apply("abc"), "abc");
+        assert_eq!(ex.to_string(), "Swap the 2nd and 5th letters");
+    }
+
+    #[test]
+    fn reverse() {
+        assert_eq!(Rule::reverse().apply("bob"), "bob");
+        assert_eq!(Rule::reverse().apply("stressed"), "desserts");
+        assert_eq!(Rule::reverse().apply(""), "");
+
+        let ex = Rule::reverse_between('x');
+        assert_eq!(ex.apply("axbcdxe"), "axdcbxe");
+        assert_eq!(ex.apply("xabxcdxefx"), "xbaxcdxfex");
+        assert_eq!(ex.apply("xabxcdx"), "xbaxcdx");
+        assert_eq!(ex.apply("abc"), "abc");
+        assert_eq!(Rule::reverse_between('r').apply("abracadabra"), "abrbadacara");
+    }
+
+    #[test]
+    fn shift() {
+        assert_eq!(Rule::shift(LetterClass::Letters, 1).apply("hal"), "ibm");
+        assert_eq!(Rule::shift(LetterClass::Letters, 27).apply("zebra"), "afcsb");
+        assert_eq!(Rule::shift(LetterClass::Vowels, 1).apply("banana"), "benene");
+        assert_eq!(Rule::shift(LetterClass::Vowels, 1).apply("tofu"), "tufa");
+        assert_eq!(Rule::shift(LetterClass::Consonants, 1).apply("abz"), "acb");
+        assert_eq!(Rule::shift(LetterClass::Consonants, 0).apply("abz"), "abz");
+
+        assert_eq!(Rule::shift(LetterClass::Vowels, 1).invert("tufa", 10).words, ["tofu"]);
+    }
+
+    #[test]
+    fn serde() {
+        for rule in [
+            Rule::convert('a', 'b'),
+            Rule::reverse(),
+            Rule::reverse_between('x'),
+            Rule::shift(LetterClass::Consonants, 3),
+            Rule::remove(Target::Vowel),
+            Rule::duplicate(Target::Set(vec!['a', 'b']), 2),
+            Rule::swap(2, 5),
+            Rule::insert(Target::Consonant, 'e', Side::Before),
+            Rule::convert('a', 'b').with_occurrence(Occurrence::Nth(2)),
+            Rule::remove('a').with_occurrence(Occurrence::Last),
+        ] {
+            let json = serde_json::to_string(&rule).unwrap();
+            assert_eq!(serde_json::from_str::<Rule>(&json).unwrap(), rule);
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Rule>(r#"{ "Reverse": { "between": "x" } }"#).unwrap(),
+            Rule::reverse_between('x')
+        );
+
+        // rules from before targets could be sets or classes
+        assert_eq!(
+            serde_json::from_str::<Rule>(r#"{ "Convert": { "target": "r", "destination": "e" } }"#).unwrap(),
+            Rule::convert('r', 'e')
+        );
+        assert_eq!(
+            serde_json::from_str::<Rule>(r#"{ "Remove": "h" }"#).unwrap(),
+            Rule::remove('h')
+        );
+        assert_eq!(
+            serde_json::from_str::<Rule>(r#"{ "Remove": "Consonant" }"#).unwrap(),
+            Rule::remove(Target::Consonant)
+        );
+        assert_eq!(
+            serde_json::from_str::<Rule>(r#"{ "Remove": { "target": "h", "occurrence": "First" } }"#).unwrap(),
+            Rule::remove('h').with_occurrence(Occurrence::First)
+        );
+        assert!(crate::load_challenges().is_empty() == false);
+    }
+
+    #[test]
+    fn invert() {
+        assert_eq!(
+            Rule::convert('b', 'a').invert("aoa", 10).words,
+            ["aoa", "aob", "boa", "bob"]
+        );
+        assert!(Rule::convert('b', 'a').invert("bob", 10).words.is_empty());
+
+        assert_eq!(Rule::duplicate('b', 3).invert("bbbobbb", 10).words, ["bob"]);
+        assert!(Rule::duplicate('b', 3).invert("bbobbb", 10).words.is_empty());
+
+        assert_eq!(
+            Rule::remove('b').invert("o", 3).words,
+            ["bbo", "bo", "bob", "o", "ob", "obb"]
+        );
+        assert!(Rule::remove('b').invert("bo", 3).words.is_empty());
+
+        assert_eq!(Rule::switch('a', 'b').invert("baba", 10).words, ["abab", "abba"]);
+        assert_eq!(
+            Rule::switch('o', 'l').invert("dllrbeoos", 10).words,
+            ["dllrbeoos", "dlorbelos", "dlorbeols", "doorbells"]
+        );
+
+        for rule in [
+            Rule::convert('b', 'a'),
+            Rule::duplicate('b', 2),
+            Rule::remove('b'),
+            Rule::switch('a', 'b'),
+            Rule::reverse(),
+            Rule::reverse_between('b'),
+            Rule::shift(LetterClass::Vowels, 2),
+            Rule::shift(LetterClass::Letters, 25),
+            Rule::convert(Target::Vowel, 'a'),
+            Rule::duplicate(Target::Consonant, 2),
+            Rule::remove(Target::Set(vec!['a', 'o'])),
+            Rule::switch(Target::Vowel, 'b'),
+            Rule::swap(0, 3),
+            Rule::insert('b', 'a', Side::After),
+            Rule::insert('a', 'a', Side::Before),
+            Rule::insert('b', 'o', Side::Before).with_occurrence(Occurrence::Last),
+            Rule::convert('b', 'a').with_occurrence(Occurrence::First),
+            Rule::duplicate('b', 2).with_occurrence(Occurrence::EveryOther),
+            Rule::duplicate('b', 0).with_occurrence(Occurrence::Last),
+            Rule::remove('b').with_occurrence(Occurrence::Nth(1)),
+            Rule::switch('a', 'b').with_occurrence(Occurrence::First),
+        ] {
+            for input in ["abba", "bob", "aobobabrt", "ccc"] {
+                let output = rule.apply(input);
+                assert!(
+                    rule.invert(&output, 10).words.contains(&input.to_string()),
+                    "{} on {}",
+                    rule,
+                    input
+                );
+            }
+        }
+    }
 }
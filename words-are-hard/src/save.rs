@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, ops::Range};
+
+use crate::{Date, GameMode, GeneratorConfig, Language, RoundStats, Rule, WordData};
+
+/// The version of the save format [`Game::save`](crate::Game::save) writes. Bump it whenever old saves would
+/// load wrong, and teach [`Game::load`](crate::Game::load) to upgrade them.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Everything about an in-progress game which is needed to pick it back up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SaveFile {
+    pub version: u32,
+    pub rules: Vec<Rule>,
+    pub words: Vec<WordData>,
+    pub chains: Vec<Range<usize>>,
+    pub language: SavedLanguage,
+    pub mode: GameMode,
+    /// The day a daily game is for.
+    #[serde(default)]
+    pub date: Option<Date>,
+    pub generator: Option<Generator>,
+    pub best_depth: usize,
+    pub current_round: usize,
+    /// The current round's time is counted up to when the game was saved.
+    pub stats: Vec<RoundStats>,
+}
+
+/// The language a save is in. Built in languages are saved as their code, and the rest with all their words.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum SavedLanguage {
+    Builtin(String),
+    Custom(Language),
+}
+
+impl From<&Language> for SavedLanguage {
+    fn from(language: &Language) -> Self {
+        match &language.code {
+            Some(code) => SavedLanguage::Builtin(code.clone()),
+            None => SavedLanguage::Custom(language.clone()),
+        }
+    }
+}
+
+impl TryFrom<SavedLanguage> for Language {
+    type Error = LoadError;
+
+    fn try_from(saved: SavedLanguage) -> Result<Self, Self::Error> {
+        match saved {
+            SavedLanguage::Builtin(code) => Language::builtin(&code).ok_or(LoadError::Language(code)),
+            SavedLanguage::Custom(language) => Ok(language),
+        }
+    }
+}
+
+/// An endless game's generator config and seed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Generator {
+    pub config: GeneratorConfig,
+    pub seed: u64,
+}
+
+/// Just enough of a save to tell which version it is.
+#[derive(Deserialize)]
+pub(crate) struct SaveVersion {
+    pub version: u32,
+}
+
+/// Why a save couldn't be loaded.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The save isn't valid.
+    Json(serde_json::Error),
+    /// The save was made by a newer version of the game.
+    Version(u32),
+    /// The save's valid, but its rounds don't add up.
+    Corrupt,
+    /// The save is in a built in language which this version of the game doesn't have.
+    Language(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Json(e) => write!(f, "the save is broken: {}", e),
+            LoadError::Version(version) => write!(
+                f,
+                "the save is version {}, but only versions up to {} can be loaded",
+                version, SAVE_VERSION
+            ),
+            LoadError::Corrupt => write!(f, "the save's rounds don't add up"),
+            LoadError::Language(code) => write!(f, "the save is in '{}', which isn't a language the game has", code),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<serde_json::Error> for LoadError {
+    fn from(e: serde_json::Error) -> Self {
+        LoadError::Json(e)
+    }
+}
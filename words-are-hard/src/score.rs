@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+use crate::RoundStats;
+
+/// How rounds are scored. Every solved round starts out at `base` points and loses some for each extra attempt,
+/// each hint and each second over par, and then gets multiplied by the streak it's on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreFormula {
+    /// Points for solving a round.
+    pub base: u32,
+    /// Taken off for each attempt after the first.
+    pub attempt_penalty: u32,
+    /// Taken off for each level of hints.
+    pub hint_penalty: u32,
+    /// How long a round can take before time starts costing points.
+    pub par: Duration,
+    /// Taken off for each whole second over par.
+    pub time_penalty: u32,
+    /// The fewest points a solved round can be worth before its multiplier.
+    pub min_points: u32,
+    /// Each round in a row solved on the first attempt without hints adds this much to the multiplier of the next
+    /// one, as long as it's solved that way too.
+    pub streak_bonus: f32,
+    /// The multiplier never goes past this.
+    pub max_multiplier: f32,
+}
+
+impl ScoreFormula {
+    /// Scores a single round, given the multiplier it's on.
+    pub fn points(&self, stats: &RoundStats, multiplier: f32) -> u32 {
+        // counts which don't fit are as good as infinite
+        let clamp = |count: u64| u32::try_from(count).unwrap_or(u32::MAX);
+        let over_par = clamp(stats.elapsed.saturating_sub(self.par).as_secs());
+        let penalty = self
+            .attempt_penalty
+            .saturating_mul(clamp(stats.attempts.saturating_sub(1) as u64))
+            .saturating_add(self.hint_penalty.saturating_mul(clamp(stats.hints as u64)))
+            .saturating_add(self.time_penalty.saturating_mul(over_par));
+
+        let points = self.base.saturating_sub(penalty).max(self.min_points);
+        (points as f32 * multiplier).round() as u32
+    }
+}
+
+impl Default for ScoreFormula {
+    fn default() -> Self {
+        Self {
+            base: 1000,
+            attempt_penalty: 100,
+            hint_penalty: 200,
+            par: Duration::from_secs(60),
+            time_penalty: 2,
+            min_points: 100,
+            streak_bonus: 0.5,
+            max_multiplier: 3.0,
+        }
+    }
+}
+
+/// How a single round was scored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundScore {
+    pub stats: RoundStats,
+    pub solved: bool,
+    /// How many rounds in a row, this one included, were solved on the first attempt without hints.
+    pub streak: usize,
+    pub multiplier: f32,
+    pub points: u32,
+}
+
+/// How a whole game was scored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scorecard {
+    pub rounds: Vec<RoundScore>,
+    pub score: u32,
+}
+
+impl Scorecard {
+    /// Scores every round, given how many of them have been played. Failed rounds don't count as solved.
+    pub fn new(stats: &[RoundStats], played: usize, formula: &ScoreFormula) -> Self {
+        let mut rounds = Vec::with_capacity(stats.len());
+        let mut streak = 0;
+
+        for (round, stats) in stats.iter().enumerate() {
+            let solved = round < played && stats.failed == false;
+            let mut multiplier = 1.0;
+
+            if solved && stats.attempts <= 1 && stats.hints == 0 {
+                multiplier = (1.0 + formula.streak_bonus * streak as f32).min(formula.max_multiplier);
+                streak += 1;
+            } else {
+                streak = 0;
+            }
+
+            rounds.push(RoundScore {
+                stats: *stats,
+                solved,
+                streak,
+                multiplier,
+                points: if solved { formula.points(stats, multiplier) } else { 0 },
+            });
+        }
+
+        let score = rounds.iter().fold(0u32, |score, v| score.saturating_add(v.points));
+
+        Self { rounds, score }
+    }
+
+    pub fn attempts(&self) -> usize {
+        self.rounds.iter().map(|v| v.stats.attempts).sum()
+    }
+
+    pub fn hints(&self) -> usize {
+        self.rounds.iter().map(|v| v.stats.hints).sum()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.rounds.iter().map(|v| v.stats.elapsed).sum()
+    }
+
+    /// The longest run of rounds solved on the first attempt without hints.
+    pub fn best_streak(&self) -> usize {
+        self.rounds.iter().map(|v| v.streak).max().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Scorecard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, round) in self.rounds.iter().enumerate() {
+            if round.solved == false && round.stats.attempts == 0 {
+                break;
+            }
+
+            write!(
+                f,
+                "{}. {} attempts, {} hints, {}: ",
+                i + 1,
+                round.stats.attempts,
+                round.stats.hints,
+                clock(round.stats.elapsed)
+            )?;
+            if round.solved {
+                write!(f, "{} points", round.points)?;
+                if round.multiplier > 1.0 {
+                    write!(f, " (x{})", round.multiplier)?;
+                }
+                writeln!(f)?;
+            } else {
+                writeln!(f, "not solved")?;
+            }
+        }
+
+        write!(
+            f,
+            "Score: {} ({} attempts, {} hints, {}, best streak {})",
+            self.score,
+            self.attempts(),
+            self.hints(),
+            clock(self.elapsed()),
+            self.best_streak()
+        )
+    }
+}
+
+/// Writes a duration like `1:05`.
+fn clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(attempts: usize, hints: usize, secs: u64) -> RoundStats {
+        RoundStats {
+            attempts,
+            hints,
+            elapsed: Duration::from_secs(secs),
+            failed: false,
+        }
+    }
+
+    #[test]
+    fn scorecard() {
+        let formula = ScoreFormula::default();
+        let card = Scorecard::new(
+            &[
+                stats(1, 0, 10),
+                stats(1, 0, 10),
+                stats(3, 1, 70),
+                stats(1, 0, 10),
+                stats(2, 0, 10),
+            ],
+            4,
+            &formula,
+        );
+
+        let points: Vec<u32> = card.rounds.iter().map(|v| v.points).collect();
+        assert_eq!(points, [1000, 1500, 580, 1000, 0]);
+        assert_eq!(card.score, 4080);
+        assert_eq!(card.best_streak(), 2);
+        assert_eq!(card.attempts(), 8);
+        assert_eq!(card.elapsed(), Duration::from_secs(110));
+    }
+
+    #[test]
+    fn formula() {
+        let formula = ScoreFormula {
+            max_multiplier: 1.5,
+            ..ScoreFormula::default()
+        };
+
+        assert_eq!(formula.points(&stats(20, 4, 1000), 1.0), 100);
+        assert_eq!(formula.points(&stats(1, 0, 0), 1.5), 1500);
+
+        let card = Scorecard::new(&[stats(1, 0, 0); 4], 4, &formula);
+        assert_eq!(card.score, 1000 + 1500 * 3);
+    }
+
+    #[test]
+    fn huge_formula() {
+        let formula = ScoreFormula {
+            base: u32::MAX,
+            attempt_penalty: u32::MAX,
+            hint_penalty: u32::MAX,
+            time_penalty: u32::MAX,
+            min_points: 0,
+            ..ScoreFormula::default()
+        };
+
+        assert_eq!(formula.points(&stats(3, 2, u64::MAX), 1.0), 0);
+        assert_eq!(formula.points(&stats(1, 0, 0), 1.0), u32::MAX);
+
+        let card = Scorecard::new(&[stats(1, 0, 0); 3], 3, &formula);
+        assert_eq!(card.score, u32::MAX);
+    }
+}
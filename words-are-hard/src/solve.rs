@@ -0,0 +1,216 @@
+use std::collections::BTreeSet;
+
+use crate::Rule;
+
+/// The longest word in [`WordList::embedded`](crate::WordList::embedded). `solve` won't come up with anything longer than this.
+pub const MAX_SOLVE_LEN: usize = 18;
+
+/// The most secrets `solve` keeps track of at once. Rules which target a class of letters can be undone in so many
+/// ways that finding all of them would never finish, so past this the rest are given up on.
+pub const MAX_CANDIDATES: usize = 10_000;
+
+/// Words which could have been turned into another one, sorted and without duplicates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Solutions {
+    pub words: Vec<String>,
+    /// Set when there were too many to find, so some were given up on.
+    pub truncated: bool,
+}
+
+/// Finds every secret which the rules, executed from top to bottom, turn into the hard word.
+///
+/// This is exhaustive, not a dictionary lookup, so most of what comes back isn't a real word. Use [`solve_in`] to
+/// only get the ones in a dictionary. Rules which could have come from more than [`MAX_CANDIDATES`] secrets only
+/// get some of them back, and say so.
+pub fn solve(rules: &[Rule], hard_word: &str) -> Solutions {
+    solve_with_limit(rules, hard_word, MAX_SOLVE_LEN.max(hard_word.chars().count()))
+}
+
+/// Like [`solve`], but never considers secrets longer than `max_len` chars. The words in between can be longer, as
+/// long as a secret that short could have grown into them.
+///
+/// Every `Remove` rule can multiply the number of candidates by a lot, so keep this small when there are a few.
+pub fn solve_with_limit(rules: &[Rule], hard_word: &str, max_len: usize) -> Solutions {
+    // duplicating letters which get removed straight after does nothing, but undoing it
+    // would mean putting back every multiple of the count, which blows the budget
+    let rules: Vec<&Rule> = rules
+        .iter()
+        .enumerate()
+        .filter(|(i, rule)| {
+            rules
+                .get(i + 1)
+                .is_none_or(|next| removes_duplicates(rule, next) == false)
+        })
+        .map(|(_, rule)| rule)
+        .collect();
+
+    // the longest each word along the way could be, starting with the secret
+    let mut limits = vec![max_len];
+    for rule in &rules {
+        limits.push(rule.max_output_len(*limits.last().unwrap()));
+    }
+
+    // we walk the rules backwards, undoing the last one first
+    let mut candidates = BTreeSet::new();
+    candidates.insert(hard_word.to_string());
+    let mut truncated = false;
+
+    for (rule, max_len) in rules.iter().zip(limits).rev() {
+        let mut inputs = BTreeSet::new();
+        for candidate in candidates.iter() {
+            if inputs.len() >= MAX_CANDIDATES {
+                truncated = true;
+                break;
+            }
+            let solutions = rule.invert(candidate, max_len);
+            inputs.extend(solutions.words);
+            truncated |= solutions.truncated;
+        }
+
+        candidates = inputs;
+    }
+
+    Solutions {
+        words: candidates.into_iter().collect(),
+        truncated,
+    }
+}
+
+/// Checks if `next` removes everything `rule` duplicated, and nothing more depends on it.
+fn removes_duplicates(rule: &Rule, next: &Rule) -> bool {
+    match (rule, next) {
+        (Rule::Duplicate(dup), Rule::Remove(remove)) => {
+            let removed = remove.target.chars();
+            dup.occurrence.is_all()
+                && remove.occurrence.is_all()
+                && dup.target.chars().iter().all(|chr| removed.contains(chr))
+        }
+        _ => false,
+    }
+}
+
+/// Finds every word in the dictionary which the rules turn into the hard word, in dictionary order.
+pub fn solve_in<I, S>(rules: &[Rule], hard_word: &str, dictionary: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    // running a word forward is far cheaper than enumerating everything backwards,
+    // and it doesn't need a length limit.
+    dictionary
+        .into_iter()
+        .filter(|word| apply_all(rules, word.as_ref()) == hard_word)
+        .map(|word| word.as_ref().to_string())
+        .collect()
+}
+
+/// Executes every rule from top to bottom on the input.
+pub fn apply_all(rules: &[Rule], input: &str) -> String {
+    rules.iter().fold(input.to_string(), |word, rule| rule.apply(&word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Occurrence, Target, WordList};
+
+    #[test]
+    fn solves_back_to_the_secret() {
+        let rules = [
+            Rule::convert('r', 'e'),
+            Rule::convert('i', 't'),
+            Rule::duplicate('c', 2),
+            Rule::switch('c', 'e'),
+        ];
+
+        for secret in ["arbitrary", "warranties", "helicopter", "convicted"] {
+            let hard_word = apply_all(&rules, secret);
+            let candidates = solve(&rules, &hard_word).words;
+
+            assert!(
+                candidates.contains(&secret.to_string()),
+                "{} not in {:?}",
+                secret,
+                candidates
+            );
+            for candidate in candidates {
+                assert_eq!(apply_all(&rules, &candidate), hard_word);
+            }
+        }
+    }
+
+    #[test]
+    fn secrets_can_grow_past_the_limit() {
+        for rules in [
+            vec![Rule::duplicate('a', 4), Rule::reverse()],
+            vec![Rule::duplicate('a', 4), Rule::remove('a')],
+        ] {
+            let hard_word = apply_all(&rules, "abracadabra");
+            let candidates = solve(&rules, &hard_word).words;
+
+            assert!(candidates.contains(&"abracadabra".to_string()), "{:?}", rules);
+        }
+    }
+
+    #[test]
+    fn finds_every_merge() {
+        let rules = [Rule::convert('a', 'b'), Rule::remove('c')];
+
+        let candidates = solve_with_limit(&rules, "bb", 3).words;
+
+        // each 'b' could have been an 'a', and a 'c' could be in any of the three gaps
+        assert_eq!(candidates.len(), 16);
+        for candidate in ["aa", "ab", "ba", "bb", "cab", "bcb", "aac"] {
+            assert!(
+                candidates.contains(&candidate.to_string()),
+                "{} not in {:?}",
+                candidate,
+                candidates
+            );
+        }
+    }
+
+    #[test]
+    fn gives_up_on_too_many_candidates() {
+        let rules = [Rule::convert(Target::Any, 'x')];
+
+        let solutions = solve(&rules, "xxxxxx");
+        assert!(solutions.truncated);
+        assert_eq!(solutions.words.len(), MAX_CANDIDATES);
+        for candidate in solutions.words {
+            assert_eq!(apply_all(&rules, &candidate), "xxxxxx");
+        }
+
+        let rules = [
+            Rule::remove(Target::Consonant),
+            Rule::convert(Target::Any, 'x').with_occurrence(Occurrence::First),
+        ];
+        assert!(solve(&rules, "xaxa").words.len() <= MAX_CANDIDATES);
+    }
+
+    #[test]
+    fn solves_class_targets_without_giving_up() {
+        let rules = [
+            Rule::convert(Target::Vowel, 'e').with_occurrence(Occurrence::First),
+            Rule::remove(Target::Set(vec!['r', 's'])),
+            Rule::duplicate(Target::Consonant, 2),
+        ];
+        let hard_word = apply_all(&rules, "carrot");
+
+        let solutions = solve_with_limit(&rules, &hard_word, 6);
+        assert!(solutions.truncated == false);
+        assert!(solutions.words.contains(&"carrot".to_string()));
+    }
+
+    #[test]
+    fn solves_in_dictionary() {
+        let rules = [Rule::convert('r', 'e')];
+        let hard_word = apply_all(&rules, "arbitrary");
+
+        assert_eq!(solve_in(&rules, &hard_word, &WordList::embedded()), ["arbitrary"]);
+        assert_eq!(
+            solve_in(&rules, &hard_word, ["arbitrary", "aebitraey", "abitrary"]),
+            ["arbitrary", "aebitraey"]
+        );
+    }
+}
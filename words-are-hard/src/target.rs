@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::LetterClass;
+
+/// The chars a rule acts on.
+///
+/// In JSON, a single char is stored as a bare string like `"r"`, a set as a list like `["a", "b"]`, and the classes
+/// by name, like `"Vowel"`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "TargetRepr", into = "TargetRepr")]
+pub enum Target {
+    Char(char),
+    Set(Vec<char>),
+    Vowel,
+    Consonant,
+    Any,
+}
+
+impl Target {
+    /// Checks if the target acts on the given char.
+    pub fn matches(&self, chr: char) -> bool {
+        match self {
+            Target::Char(target) => *target == chr,
+            Target::Set(set) => set.contains(&chr),
+            Target::Vowel => LetterClass::Vowels.contains(chr),
+            Target::Consonant => LetterClass::Consonants.contains(chr),
+            Target::Any => LetterClass::Letters.contains(chr),
+        }
+    }
+
+    /// Every char the target acts on.
+    pub fn chars(&self) -> Vec<char> {
+        match self {
+            Target::Char(target) => vec![*target],
+            Target::Set(set) => set.clone(),
+            Target::Vowel => LetterClass::Vowels.letters(),
+            Target::Consonant => LetterClass::Consonants.letters(),
+            Target::Any => LetterClass::Letters.letters(),
+        }
+    }
+
+    /// Gets the char, if this targets a single char.
+    pub fn as_char(&self) -> Option<char> {
+        match self {
+            Target::Char(target) => Some(*target),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Target::Char(char::default())
+    }
+}
+
+impl From<char> for Target {
+    fn from(chr: char) -> Self {
+        Target::Char(chr)
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Char(target) => write!(f, "{}", target),
+            Target::Set(set) => write!(f, "any of {}", set.iter().collect::<String>()),
+            Target::Vowel => write!(f, "any vowel"),
+            Target::Consonant => write!(f, "any consonant"),
+            Target::Any => write!(f, "any letter"),
+        }
+    }
+}
+
+/// How a [`Target`] looks in JSON. This keeps single chars as bare chars, like they were before there were sets and
+/// classes.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum TargetRepr {
+    Char(char),
+    Set(Vec<char>),
+    Class(TargetClass),
+}
+
+#[derive(Serialize, Deserialize)]
+enum TargetClass {
+    Vowel,
+    Consonant,
+    Any,
+}
+
+impl From<TargetRepr> for Target {
+    fn from(repr: TargetRepr) -> Self {
+        match repr {
+            TargetRepr::Char(chr) => Target::Char(chr),
+            TargetRepr::Set(set) => Target::Set(set),
+            TargetRepr::Class(TargetClass::Vowel) => Target::Vowel,
+            TargetRepr::Class(TargetClass::Consonant) => Target::Consonant,
+            TargetRepr::Class(TargetClass::Any) => Target::Any,
+        }
+    }
+}
+
+impl From<Target> for TargetRepr {
+    fn from(target: Target) -> Self {
+        match target {
+            Target::Char(chr) => TargetRepr::Char(chr),
+            Target::Set(set) => TargetRepr::Set(set),
+            Target::Vowel => TargetRepr::Class(TargetClass::Vowel),
+            Target::Consonant => TargetRepr::Class(TargetClass::Consonant),
+            Target::Any => TargetRepr::Class(TargetClass::Any),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        for (target, json) in [
+            (Target::Char('r'), r#""r""#),
+            (Target::Set(vec!['a', 'b']), r#"["a","b"]"#),
+            (Target::Vowel, r#""Vowel""#),
+            (Target::Consonant, r#""Consonant""#),
+            (Target::Any, r#""Any""#),
+        ] {
+            assert_eq!(serde_json::to_string(&target).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Target>(json).unwrap(), target);
+        }
+    }
+
+    #[test]
+    fn matches() {
+        assert!(Target::Vowel.matches('e'));
+        assert!(Target::Vowel.matches('t') == false);
+        assert!(Target::Consonant.matches('t'));
+        assert!(Target::Consonant.matches('-') == false);
+        assert!(Target::Any.matches('q'));
+        assert!(Target::Set(vec!['x', 'y']).matches('y'));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{rule::switch_sources, Rule, Side};
+
+/// What a rule did to a char on its way through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    /// The rule left the char alone.
+    Kept,
+    /// The rule converted the char from the given char.
+    Converted(char),
+    /// The char is one of the copies made by a duplication. The original is copy `0`.
+    Duplicated(usize),
+    /// The rule moved the char here from somewhere else.
+    Moved,
+    /// The rule inserted the char next to the char it came from. Before that, it's that char's history.
+    Inserted,
+}
+
+/// A char in the output of a rule, and where it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TracedChar {
+    pub chr: char,
+    /// The index of the char in the rule's input which this came from.
+    pub source: usize,
+    /// The index of the char in the secret which this came from.
+    pub origin: usize,
+    pub effect: Effect,
+}
+
+/// A single rule's worth of a [`Trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub rule: Rule,
+    pub output: Vec<TracedChar>,
+    /// The indices of the chars in the rule's input which the rule deleted.
+    pub deleted: Vec<usize>,
+}
+
+impl TraceStep {
+    /// The output of the rule as a string.
+    pub fn output_string(&self) -> String {
+        self.output.iter().map(|v| v.chr).collect()
+    }
+}
+
+/// Every intermediate word between a secret and its hard word, with where every char came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub secret: String,
+    pub steps: Vec<TraceStep>,
+}
+
+impl Trace {
+    /// The word at the end of the trace.
+    pub fn hard_word(&self) -> String {
+        match self.steps.last() {
+            Some(step) => step.output_string(),
+            None => self.secret.clone(),
+        }
+    }
+
+    /// Follows a char of the hard word back to the secret, giving the index of each rule which did something to it
+    /// and what it did, from the first rule to the last.
+    pub fn history(&self, index: usize) -> Vec<(usize, Effect)> {
+        let mut history = vec![];
+
+        let mut index = index;
+        for (rule_idx, step) in self.steps.iter().enumerate().rev() {
+            let traced = step.output[index];
+            if traced.effect != Effect::Kept {
+                history.push((rule_idx, traced.effect));
+            }
+            index = traced.source;
+        }
+        history.reverse();
+
+        history
+    }
+}
+
+/// Executes every rule from top to bottom on the secret, keeping track of every step.
+pub fn trace(rules: &[Rule], secret: &str) -> Trace {
+    let mut steps: Vec<TraceStep> = Vec::with_capacity(rules.len());
+    let mut word = secret.to_string();
+
+    for rule in rules {
+        let mut step = rule.apply_traced(&word);
+
+        if let Some(previous) = steps.last() {
+            for traced in step.output.iter_mut() {
+                traced.origin = previous.output[traced.source].origin;
+            }
+        }
+
+        word = step.output_string();
+        steps.push(step);
+    }
+
+    Trace {
+        secret: secret.to_string(),
+        steps,
+    }
+}
+
+impl Rule {
+    /// Like [`Rule::apply`], but keeps track of where every output char came from.
+    ///
+    /// The `origin` of each char is its index in the input, since that's as far back as a single rule can see.
+    pub fn apply_traced(&self, input: &str) -> TraceStep {
+        let input: Vec<char> = input.chars().collect();
+        let mut output = Vec::with_capacity(input.len());
+        let mut deleted = vec![];
+
+        let mut push = |chr: char, source: usize, effect: Effect| {
+            output.push(TracedChar {
+                chr,
+                source,
+                origin: source,
+                effect,
+            })
+        };
+
+        // a `When` acts just like the rule inside it, on fewer chars
+        let selected = self.selection(&input);
+
+        match self.innermost() {
+            Rule::Convert(cnv) => {
+                for (i, chr) in input.iter().copied().enumerate() {
+                    if selected[i] && chr != cnv.destination {
+                        push(cnv.destination, i, Effect::Converted(chr));
+                    } else {
+                        push(chr, i, Effect::Kept);
+                    }
+                }
+            }
+            Rule::Duplicate(dup) => {
+                for (i, chr) in input.iter().copied().enumerate() {
+                    if selected[i] == false {
+                        push(chr, i, Effect::Kept);
+                    } else if dup.count == 0 {
+                        deleted.push(i);
+                    } else if dup.count == 1 {
+                        push(chr, i, Effect::Kept);
+                    } else {
+                        for copy in 0..dup.count {
+                            push(chr, i, Effect::Duplicated(copy));
+                        }
+                    }
+                }
+            }
+            Rule::Remove(_) => {
+                for (i, chr) in input.iter().copied().enumerate() {
+                    if selected[i] {
+                        deleted.push(i);
+                    } else {
+                        push(chr, i, Effect::Kept);
+                    }
+                }
+            }
+            Rule::Switch(td) => {
+                for (i, source) in switch_sources(td, &input, &selected).into_iter().enumerate() {
+                    let effect = if source == i { Effect::Kept } else { Effect::Moved };
+                    push(input[source], source, effect);
+                }
+            }
+            Rule::Reverse(rev) => {
+                for (i, source) in rev.sources(&input).into_iter().enumerate() {
+                    let effect = if source == i { Effect::Kept } else { Effect::Moved };
+                    push(input[source], source, effect);
+                }
+            }
+            Rule::Shift(shift) => {
+                for (i, chr) in input.iter().copied().enumerate() {
+                    let shifted = if selected[i] { shift.shift(chr) } else { chr };
+                    let effect = if shifted == chr {
+                        Effect::Kept
+                    } else {
+                        Effect::Converted(chr)
+                    };
+                    push(shifted, i, effect);
+                }
+            }
+            Rule::Swap(swap) => {
+                for (i, source) in swap.sources(input.len()).into_iter().enumerate() {
+                    let effect = if source == i { Effect::Kept } else { Effect::Moved };
+                    push(input[source], source, effect);
+                }
+            }
+            Rule::Insert(ins) => {
+                for (i, chr) in input.iter().copied().enumerate() {
+                    if selected[i] && ins.side == Side::Before {
+                        push(ins.letter, i, Effect::Inserted);
+                    }
+                    push(chr, i, Effect::Kept);
+                    if selected[i] && ins.side == Side::After {
+                        push(ins.letter, i, Effect::Inserted);
+                    }
+                }
+            }
+            Rule::When(_) => unreachable!("`innermost` never gives a `When`"),
+        }
+
+        TraceStep {
+            rule: self.clone(),
+            output,
+            deleted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, LetterClass, Occurrence, Target};
+
+    #[test]
+    fn traced_matches_apply() {
+        for rule in [
+            Rule::convert('b', 'a'),
+            Rule::convert('b', 'b'),
+            Rule::duplicate('b', 3),
+            Rule::duplicate('b', 0),
+            Rule::remove('b'),
+            Rule::switch('a', 'b'),
+            Rule::switch('o', 'l'),
+            Rule::switch('a', 'a'),
+            Rule::reverse(),
+            Rule::reverse_between('b'),
+            Rule::shift(LetterClass::Vowels, 3),
+            Rule::swap(0, 2),
+            Rule::convert('b', 'a').with_occurrence(Occurrence::Last),
+            Rule::duplicate('b', 2).with_occurrence(Occurrence::First),
+            Rule::remove('b').with_occurrence(Occurrence::EveryOther),
+            Rule::switch('o', 'l').with_occurrence(Occurrence::Nth(1)),
+            Rule::convert('b', 'x').when(Context::Before(Target::Vowel)),
+            Rule::switch('b', 'a').when(Context::After(Target::Char('o'))),
+            Rule::shift(LetterClass::Letters, 1).when(Context::After(Target::Char('b'))),
+            Rule::insert('b', 'x', Side::Before),
+            Rule::insert(Target::Vowel, 'x', Side::After).with_occurrence(Occurrence::First),
+        ] {
+            for input in ["abba", "bob", "aobobabrt", "doorbells", "", "ccc"] {
+                assert_eq!(
+                    rule.apply_traced(input).output_string(),
+                    rule.apply(input),
+                    "{} on {}",
+                    rule,
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn switch_sources() {
+        let step = Rule::switch('o', 'l').apply_traced("doorbells");
+        let sources: Vec<_> = step.output.iter().map(|v| v.source).collect();
+
+        // d o o r b e l l s
+        // the first 'o' trades with the first 'l', and the second 'o' with the second 'l'
+        assert_eq!(sources, [0, 6, 7, 3, 4, 5, 1, 2, 8]);
+        assert_eq!(step.output[1].effect, Effect::Moved);
+        assert_eq!(step.output[0].effect, Effect::Kept);
+
+        // orphans stay put
+        let step = Rule::switch('a', 'b').apply_traced("bca");
+        assert!(step.output.iter().all(|v| v.effect == Effect::Kept));
+    }
+
+    #[test]
+    fn traces_back_to_the_secret() {
+        let rules = [
+            Rule::convert('o', 'a'),
+            Rule::duplicate('a', 2),
+            Rule::remove('b'),
+            Rule::switch('a', 's'),
+        ];
+        let trace = trace(&rules, "bobs");
+
+        let words: Vec<_> = trace.steps.iter().map(|v| v.output_string()).collect();
+        assert_eq!(words, ["babs", "baabs", "aas", "saa"]);
+        assert_eq!(trace.hard_word(), "saa");
+        assert_eq!(trace.steps[2].deleted, [0, 3]);
+
+        let origins: Vec<_> = trace.steps[3].output.iter().map(|v| v.origin).collect();
+        assert_eq!(origins, [3, 1, 1]);
+
+        assert_eq!(trace.history(0), [(3, Effect::Moved)]);
+        assert_eq!(
+            trace.history(1),
+            [(0, Effect::Converted('o')), (1, Effect::Duplicated(1))]
+        );
+        assert_eq!(
+            trace.history(2),
+            [
+                (0, Effect::Converted('o')),
+                (1, Effect::Duplicated(0)),
+                (3, Effect::Moved)
+            ]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{Rule, Target};
+
+/// Makes a rule act only on the chars whose neighbour fits the context, like converting t to d only before a vowel.
+///
+/// `Reverse` and `Swap` move the whole word around rather than acting on chars, so they ignore the context.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct When {
+    pub rule: Box<Rule>,
+    pub context: Context,
+}
+
+impl When {
+    /// The chars the rule inside could act on, as long as they fit the context too.
+    pub(crate) fn candidates(&self, input: &[char]) -> Vec<bool> {
+        self.rule
+            .candidates(input)
+            .into_iter()
+            .enumerate()
+            .map(|(i, candidate)| candidate && self.context.fits(input, i))
+            .collect()
+    }
+}
+
+/// Where a char has to be for a [`When`] to act on it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Context {
+    /// The char has to come right before one of these.
+    Before(Target),
+    /// The char has to come right after one of these.
+    After(Target),
+}
+
+impl Context {
+    /// Checks if the char at `index` fits the context.
+    pub fn fits(&self, input: &[char], index: usize) -> bool {
+        match self {
+            Context::Before(next) => input.get(index + 1).is_some_and(|chr| next.matches(*chr)),
+            Context::After(previous) => index
+                .checked_sub(1)
+                .and_then(|i| input.get(i))
+                .is_some_and(|chr| previous.matches(*chr)),
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Context::Before(next) => write!(f, "before {}", next),
+            Context::After(previous) => write!(f, "after {}", previous),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LetterClass, Occurrence};
+
+    #[test]
+    fn apply() {
+        let before_vowel = Rule::convert('t', 'd').when(Context::Before(Target::Vowel));
+        assert_eq!(before_vowel.apply("tattoo"), "datdoo");
+        assert_eq!(before_vowel.to_string(), "Convert t to d, only before any vowel");
+
+        let after_a = Rule::remove('n').when(Context::After(Target::Char('a')));
+        assert_eq!(after_a.apply("banana"), "baaa");
+        assert_eq!(after_a.apply("nun"), "nun");
+
+        // the occurrence only counts the chars which fit
+        let first = Rule::duplicate('t', 2)
+            .with_occurrence(Occurrence::First)
+            .when(Context::After(Target::Vowel));
+        assert_eq!(first.apply("tattoo"), "tatttoo");
+
+        let shift = Rule::shift(LetterClass::Vowels, 1).when(Context::Before(Target::Char('n')));
+        assert_eq!(shift.apply("banana"), "benena");
+
+        assert_eq!(Rule::reverse().when(Context::Before(Target::Any)).apply("abc"), "cba");
+    }
+
+    #[test]
+    fn serde() {
+        let rule = Rule::switch('t', 'o').when(Context::After(Target::Consonant));
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(serde_json::from_str::<Rule>(&json).unwrap(), rule);
+
+        let json = r#"{ "When": { "rule": { "Convert": { "target": "t", "destination": "d" } }, "context": { "Before": "Vowel" } } }"#;
+        assert_eq!(
+            serde_json::from_str::<Rule>(json).unwrap(),
+            Rule::convert('t', 'd').when(Context::Before(Target::Vowel))
+        );
+    }
+
+    #[test]
+    fn invert() {
+        for rule in [
+            Rule::convert('t', 'd').when(Context::Before(Target::Vowel)),
+            Rule::duplicate('t', 2).when(Context::After(Target::Char('a'))),
+            Rule::remove('n').when(Context::After(Target::Char('a'))),
+            Rule::switch('a', 't').when(Context::Before(Target::Char('t'))),
+            Rule::shift(LetterClass::Vowels, 1).when(Context::Before(Target::Char('n'))),
+        ] {
+            for input in ["tattoo", "banana", "attenuate"] {
+                let output = rule.apply(input);
+                assert!(
+                    rule.invert(&output, 10).words.contains(&input.to_string()),
+                    "{} on {}",
+                    rule,
+                    input
+                );
+                for other in rule.invert(&output, 10).words {
+                    assert_eq!(rule.apply(&other), output);
+                }
+            }
+        }
+    }
+}
@@ -1,16 +1,29 @@
 use std::collections::BTreeMap;
-use words_are_hard::{ChallengeInstruction, Game, RoundData, Rule};
+use words_are_hard::{
+    pack_difficulty, ChallengeInstruction, Game, GameMode, GameState, GeneratorConfig, GuessOutcome, RoundData, Rule,
+    ScoreFormula,
+};
 
 use dauga::{imgui::Ui, smol_rgb::EncodedRgb, utils};
 
 pub struct Gui {
     games: BTreeMap<String, ManagedGame>,
+    /// The challenges, easiest first, with their difficulty.
+    challenges: Vec<(String, f32)>,
 }
 
 impl Gui {
-    pub fn new() -> Self {
+    pub fn new(game_data: &BTreeMap<String, Vec<ChallengeInstruction>>) -> Self {
+        let mut challenges: Vec<_> = game_data
+            .iter()
+            .filter(|(n, _)| !n.contains("tutorial"))
+            .map(|(name, instructions)| (name.clone(), pack_difficulty(instructions)))
+            .collect();
+        challenges.sort_by(|a, b| a.1.total_cmp(&b.1));
+
         Self {
             games: Default::default(),
+            challenges,
         }
     }
 
@@ -28,12 +41,33 @@ impl Gui {
                 .opened(&mut &mut opened)
                 .begin()
             {
-                if let Some(round_data) = game.game.round_data() {
-                    if display_round_data(ui, &round_data, &mut game.guess, &mut game.set_keyboard_focus) {
-                        game.game.advance_game();
+                game.game.tick();
+
+                if let Some(lives) = game.game.lives_left() {
+                    ui.text(format!("Lives: {}", "\u{f004} ".repeat(lives)));
+                }
+
+                if let (GameState::Lost, Some(round_data)) = (game.game.state(), game.game.round_data()) {
+                    let secret = &round_data.word_data.secret;
+                    ui.text(format!("Out of lives! The word was {}", secret));
+                    ui.text(game.game.scorecard(&ScoreFormula::default()).to_string());
+                } else if let Some(round_data) = game.game.round_data() {
+                    if let Some(guess) =
+                        display_round_data(ui, &round_data, &mut game.guess, &mut game.set_keyboard_focus)
+                    {
+                        game.feedback = match game.game.submit_guess(&guess) {
+                            GuessOutcome::Correct | GuessOutcome::AlternativeSolution => String::new(),
+                            GuessOutcome::ValidButWrong(hard_word) => format!("{} would become {}", guess, hard_word),
+                            GuessOutcome::NotAWord => format!("{} isn't a word", guess),
+                            GuessOutcome::TimeUp => "Out of time!".to_string(),
+                        };
+                    }
+
+                    if game.feedback.is_empty() == false {
+                        ui.text(&game.feedback);
                     }
                 } else {
-                    ui.text("Good job! Thanks for playing!");
+                    ui.text(game.game.scorecard(&ScoreFormula::default()).to_string());
                 }
             }
 
@@ -62,6 +96,7 @@ impl Gui {
                                 game: Game::new_instructions(instructions),
                                 guess: String::new(),
                                 set_keyboard_focus: true,
+                                feedback: String::new(),
                             },
                         );
                     }
@@ -69,18 +104,20 @@ impl Gui {
             }
 
             if let Some(_t) = ui.begin_menu("Challenge") {
-                for (name, instructions) in game_data.iter().filter(|(n, _)| !n.contains("tutorial")) {
+                for (name, difficulty) in self.challenges.iter() {
                     if ui
                         .menu_item_config(name)
+                        .shortcut(format!("{:.1}", difficulty))
                         .selected(self.games.contains_key(name))
                         .build()
                     {
                         self.games.insert(
                             name.clone(),
                             ManagedGame {
-                                game: Game::new_instructions(instructions),
+                                game: Game::new_instructions(&game_data[name]),
                                 guess: String::new(),
                                 set_keyboard_focus: true,
+                                feedback: String::new(),
                             },
                         );
                     }
@@ -88,26 +125,65 @@ impl Gui {
 
                 ui.separator();
 
-                if ui
-                    .menu_item_config("Random")
-                    .selected(self.games.contains_key("Random"))
-                    .build()
-                {
-                    self.games.insert(
-                        "Random".to_string(),
-                        ManagedGame {
-                            game: Game::new(),
-                            guess: String::new(),
-                            set_keyboard_focus: true,
-                        },
-                    );
+                if let Some(_t) = ui.begin_menu("Random") {
+                    for (name, config, mode) in [
+                        ("Random (Easy)", GeneratorConfig::easy(), GameMode::Classic),
+                        ("Random (Medium)", GeneratorConfig::medium(), GameMode::Classic),
+                        ("Random (Hard)", GeneratorConfig::hard(), GameMode::Classic),
+                        ("Random (3 Lives)", GeneratorConfig::medium(), GameMode::Lives(3)),
+                    ] {
+                        if ui
+                            .menu_item_config(name)
+                            .selected(self.games.contains_key(name))
+                            .build()
+                        {
+                            self.games.insert(
+                                name.to_string(),
+                                ManagedGame {
+                                    game: Game::with_config(&config).with_mode(mode),
+                                    guess: String::new(),
+                                    set_keyboard_focus: true,
+                                    feedback: String::new(),
+                                },
+                            );
+                        }
+                    }
                 }
             }
         }
     }
 }
 
-fn display_round_data(ui: &Ui, round_data: &RoundData, guess: &mut String, set_keyboard_focus: &mut bool) -> bool {
+fn rule_text(rule: &Rule) -> String {
+    let mut txt = match rule {
+        Rule::Convert(cnv) => format!("{} \u{f061} {}", cnv.target, cnv.destination),
+        Rule::Duplicate(dep) => format!("{} \u{f057} {}", dep.target, dep.count),
+        Rule::Remove(rm) => format!("\u{f1f8} {}", rm.target),
+        Rule::Insert(ins) => format!("\u{f067} {} {} {}", ins.letter, ins.side, ins.target),
+        Rule::Switch(td) => format!("{} \u{f362} {}", td.target, td.destination),
+        Rule::Reverse(rev) => match rev.between {
+            Some(between) => format!("\u{f07e} {}", between),
+            None => "\u{f07e}".to_string(),
+        },
+        Rule::Shift(shift) => format!("{}s \u{f021} {}", shift.class, shift.amount),
+        Rule::Swap(swap) => format!("{} \u{f0ec} {}", swap.first + 1, swap.second + 1),
+        // the rule inside already shows its occurrence
+        Rule::When(when) => return format!("{} ({})", rule_text(&when.rule), when.context),
+    };
+    if rule.occurrence().is_all() == false {
+        txt.push_str(&format!(" ({})", rule.occurrence()));
+    }
+
+    txt
+}
+
+/// Draws the round, giving back the guess if one was submitted.
+fn display_round_data(
+    ui: &Ui,
+    round_data: &RoundData,
+    guess: &mut String,
+    set_keyboard_focus: &mut bool,
+) -> Option<String> {
     ui.text("Rules:");
     for (i, rule) in round_data.rules.iter().enumerate() {
         ui.text_colored(
@@ -116,16 +192,10 @@ fn display_round_data(ui: &Ui, round_data: &RoundData, guess: &mut String, set_k
         );
         ui.same_line();
 
-        let txt = match rule {
-            Rule::Convert(cnv) => format!("{} \u{f061} {}", cnv.target, cnv.destination),
-            Rule::Duplicate(dep) => format!("{} \u{f057} {}", dep.target, dep.count),
-            Rule::Remove(rm) => format!("\u{f1f8} {}", rm.0),
-            Rule::Switch(td) => format!("{} \u{f362} {}", td.target, td.destination),
-        };
-        ui.text(txt);
+        ui.text(rule_text(rule));
 
         if let Some(_t) = utils::raw_help_anywhere(ui) {
-            match rule {
+            match rule.innermost() {
                 Rule::Convert(_) => {
                     ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "a \u{f061} b");
                     ui.same_line();
@@ -218,6 +288,168 @@ fn display_round_data(ui: &Ui, round_data: &RoundData, guess: &mut String, set_k
                     ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "a \u{f362} g");
                     ui.text("Output: 'grgnautana'");
                 }
+                Rule::Reverse(_) => {
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "\u{f07e}");
+                    ui.same_line();
+                    ui.text(", reverses the whole word");
+
+                    ui.spacing();
+
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "\u{f07e} x");
+                    ui.same_line();
+                    ui.text(", reverses everything between the 1st and 2nd 'x', the 3rd and 4th 'x', and so on");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Input: 'stressed'");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "\u{f07e}");
+                    ui.text("Output: 'desserts'");
+
+                    ui.spacing();
+
+                    ui.text("Input: 'abracadabra'");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "\u{f07e} r");
+                    ui.text("Output: 'abrbadacara'");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Remember: rules execute top to bottom, and effects can stack:");
+                    ui.text("Input: 'drawer'");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "\u{f07e}");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "w \u{f061} d");
+                    ui.text("Output: 'redard'");
+                }
+                Rule::Shift(_) => {
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "vowels \u{f021} 1",
+                    );
+                    ui.same_line();
+                    ui.text(", moves every vowel 1 vowel along, so 'a' becomes 'e' and 'u' wraps around to 'a'");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Input: 'tofu'");
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "vowels \u{f021} 1",
+                    );
+                    ui.text("Output: 'tufa'");
+
+                    ui.spacing();
+
+                    ui.text("Input: 'hal'");
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "letters \u{f021} 1",
+                    );
+                    ui.text("Output: 'ibm'");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Remember: rules execute top to bottom, and effects can stack:");
+                    ui.text("Input: 'bone'");
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "consonants \u{f021} 2",
+                    );
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "\u{f1f8} e");
+                    ui.text("Output: 'doq'");
+                }
+                Rule::Swap(_) => {
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "2 \u{f0ec} 5");
+                    ui.same_line();
+                    ui.text(", swaps the 2nd and 5th letters. If the word is too short, nothing happens");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Input: 'parts'");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "1 \u{f0ec} 5");
+                    ui.text("Output: 'sartp'");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Remember: rules execute top to bottom, and effects can stack:");
+                    ui.text("Input: 'dog'");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "1 \u{f0ec} 3");
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "d \u{f061} b");
+                    ui.text("Output: 'gob'");
+                }
+                Rule::Insert(_) => {
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "\u{f067} x after a",
+                    );
+                    ui.same_line();
+                    ui.text(", puts an 'x' right after every 'a'");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Input: 'banana'");
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "\u{f067} r before n",
+                    );
+                    ui.text("Output: 'barnarna'");
+
+                    ui.spacing();
+                    ui.spacing();
+                    ui.spacing();
+
+                    ui.text("Remember: rules execute top to bottom, and effects can stack:");
+                    ui.text("Input: 'cat'");
+                    ui.text_colored(
+                        EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                        "\u{f067} h after c",
+                    );
+                    ui.text_colored(EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(), "a \u{f061} i");
+                    ui.text("Output: 'chit'");
+                }
+                Rule::When(_) => {}
+            }
+
+            if rule.occurrence().is_all() == false {
+                ui.spacing();
+                ui.spacing();
+                ui.spacing();
+
+                ui.text("This rule only acts on some of its letters, counting from the start of the word:");
+                ui.text_colored(
+                    EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                    format!("({})", rule.occurrence()),
+                );
+            }
+
+            if let Rule::When(when) = rule {
+                ui.spacing();
+                ui.spacing();
+                ui.spacing();
+
+                ui.text("This rule only acts on letters which fit next to their neighbours:");
+                ui.text_colored(
+                    EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                    format!("({})", when.context),
+                );
+                ui.text("Input: 'tattoo'");
+                ui.text_colored(
+                    EncodedRgb::new(251, 162, 204, 255).to_encoded_f32s(),
+                    "t \u{f061} d (before any vowel)",
+                );
+                ui.text("Output: 'datdoo'");
             }
         }
     }
@@ -253,12 +485,9 @@ fn display_round_data(ui: &Ui, round_data: &RoundData, guess: &mut String, set_k
     }
 
     if pressed_enter {
-        let success = round_data.word_data.secret == *guess;
-        guess.clear();
-
-        success
+        Some(std::mem::take(guess))
     } else {
-        false
+        None
     }
 }
 
@@ -266,4 +495,6 @@ pub struct ManagedGame {
     game: Game,
     guess: String,
     set_keyboard_focus: bool,
+    /// What came of the last guess, if it didn't solve the round.
+    feedback: String,
 }
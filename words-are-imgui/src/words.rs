@@ -34,7 +34,7 @@ impl Words {
         let game_data = words_are_hard::load_challenges().into_iter().collect();
 
         let me = Self {
-            gui: Gui::new(),
+            gui: Gui::new(&game_data),
             game_data,
             imgui_platform,
             window,
@@ -1,44 +1,220 @@
 use console::style;
+use std::path::PathBuf;
 use words_are_hard::*;
 
 fn main() {
     let mut guess: String;
 
-    let game = Game::new_instructions(&[
-        ChallengeInstruction::new(Rule::Convert(TargetDestination::new('r', 'e')), "arbitrary"),
-        ChallengeInstruction::new(Rule::Convert(TargetDestination::new('i', 't')), "warranties"),
-        ChallengeInstruction::new(Rule::Convert(TargetDestination::new('n', 'o')), "signatures"),
-        ChallengeInstruction::new(Rule::Remove(Remove('h')), "horoscope"),
-        ChallengeInstruction::new(Rule::Duplicate(Duplicate::new('c', 2)), "helicopter"),
-        ChallengeInstruction::new(Rule::Switch(TargetDestination::new('c', 'e')), "convicted"),
-    ]);
+    let mut args = std::env::args().skip(1);
+    let mut daily = None;
+    let mut random = None;
+    let mut language = None;
+    let mut words = None;
+    let mut mode = GameMode::Classic;
+    let mut endless = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--daily" => {
+                daily = Some(match args.next() {
+                    Some(date) => date.parse().unwrap_or_else(|e| {
+                        eprintln!("{}", e);
+                        std::process::exit(1);
+                    }),
+                    None => Date::today(),
+                });
+            }
+            "--random" => {
+                random = Some(match args.next().as_deref() {
+                    Some("easy") => GeneratorConfig::easy(),
+                    Some("medium") | None => GeneratorConfig::medium(),
+                    Some("hard") => GeneratorConfig::hard(),
+                    Some(other) => {
+                        eprintln!("'{}' is not easy, medium or hard", other);
+                        std::process::exit(1);
+                    }
+                });
+            }
+            "--language" => {
+                let code = args.next().unwrap_or_default();
+                language = Some(Language::builtin(&code).unwrap_or_else(|| {
+                    eprintln!("'{}' is not en, es, de or fr", code);
+                    std::process::exit(1);
+                }));
+            }
+            "--words" => {
+                let path = args.next().unwrap_or_default();
+                let list = WordList::load(&path).unwrap_or_else(|e| {
+                    eprintln!("couldn't load '{}': {}", path, e);
+                    std::process::exit(1);
+                });
+                words = Some((path, list));
+            }
+            "--lives" | "--lives-per-round" => {
+                let lives = args.next().and_then(|v| v.parse().ok()).unwrap_or_else(|| {
+                    eprintln!("{} needs a number of lives", arg);
+                    std::process::exit(1);
+                });
+                mode = if arg == "--lives" {
+                    GameMode::Lives(lives)
+                } else {
+                    GameMode::LivesPerRound(lives)
+                };
+            }
+            "--time-attack" => {
+                let secs = args.next().and_then(|v| v.parse().ok()).unwrap_or_else(|| {
+                    eprintln!("--time-attack needs a number of seconds per round");
+                    std::process::exit(1);
+                });
+                mode = GameMode::TimeAttack(std::time::Duration::from_secs(secs));
+            }
+            "--endless" => {
+                endless = Some(match args.next().as_deref() {
+                    Some("stack") | None => Overflow::Stack,
+                    Some("rotate") => Overflow::Rotate,
+                    Some(other) => {
+                        eprintln!("'{}' is not stack or rotate", other);
+                        std::process::exit(1);
+                    }
+                });
+            }
+            _ => {
+                eprintln!(
+                    "usage: words-are-text [--daily [YYYY-MM-DD]] [--random [easy|medium|hard]] [--language en|es|de|fr] \
+                     [--words FILE] [--lives N | --lives-per-round N | --time-attack SECS | --endless [stack|rotate]]"
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // endless games make up their own rounds and are scored by depth
+    if endless.is_some() && (daily.is_some() || !matches!(mode, GameMode::Classic)) {
+        eprintln!("--endless can't be used with --daily, --lives, --lives-per-round or --time-attack");
+        std::process::exit(1);
+    }
+
+    if language.is_some() || words.is_some() {
+        if daily.is_some() {
+            eprintln!("--language and --words can't be used with --daily, which is the same puzzle for everyone");
+            std::process::exit(1);
+        }
+
+        // rather than the tutorial, which is in English
+        random.get_or_insert_with(GeneratorConfig::medium);
+    }
+    let mut language = language.unwrap_or_else(Language::english);
+
+    // a themed word pack, written in the language's alphabet
+    if let Some((path, words)) = words {
+        let config = random.get_or_insert_with(GeneratorConfig::medium);
+        language = Language::new(language.name.clone(), language.alphabet.clone(), &words);
+
+        if language.words.with_len(config.word_len.clone()).is_empty() {
+            eprintln!(
+                "'{}' has no words the right length for the game which are written in the {} alphabet",
+                path, language.name
+            );
+            std::process::exit(1);
+        }
+    }
 
     let console = console::Term::stdout();
 
-    'outer: for round_data in game.iter() {
+    let resumed = load_autosave().filter(|_| {
+        dialoguer::Confirm::new()
+            .with_prompt("You have a game in progress. Resume it?")
+            .default(true)
+            .interact_on(&console)
+            .unwrap_or(false)
+    });
+    let mut game = if let Some(game) = resumed {
+        game
+    } else {
+        match (daily, &random, endless) {
+            // endless games stop adding rules once they have as many as the config has rounds
+            (None, _, Some(overflow)) => {
+                let config = random.clone().unwrap_or_else(GeneratorConfig::medium);
+                let endless = Endless {
+                    max_rules: config.rounds,
+                    overflow,
+                };
+
+                Game::endless(&config, &language, endless).with_best_depth(load_best_depth())
+            }
+            (Some(date), _, _) => Game::daily(date).with_mode(mode),
+            (None, Some(config), None) => Game::with_language(config, &language).with_mode(mode),
+            (None, None, None) => tutorial().with_mode(mode),
+        }
+    };
+
+    // a resumed game might be a daily one
+    let daily = game.date();
+
+    let mut feedback = None;
+    'outer: while game.state() == GameState::InProgress {
         let mut first_time = true;
-        let mut cheat = false;
+        let mut hints: Vec<Hint> = vec![];
         'inner: loop {
+            autosave(&game);
+
+            let round_data = game.round_data().unwrap();
             console.clear_screen().unwrap();
 
+            if let Some(date) = daily {
+                println!("{} for {}", style("Daily puzzle").yellow(), date);
+            }
+
             println!("{}: execute from top to bottom", style("Rules").yellow());
             for (i, rule) in round_data.rules.iter().enumerate() {
                 println!("{}. {}", style(i + 1).yellow(), rule);
             }
 
-            if cheat {
-                cheat = false;
-                println!("{}", round_data.word_data.secret);
+            if let Some(lives) = game.lives_left() {
+                println!("{}: {}", style("Lives").yellow(), "♥".repeat(lives));
             }
 
-            println!("Hard Word: {}", style(&round_data.word_data.hard_word).red());
+            if let GameMode::Endless(_) = game.mode() {
+                println!(
+                    "{}: {} (best {})",
+                    style("Depth").yellow(),
+                    game.current_round(),
+                    game.best_depth()
+                );
+            }
+
+            print!("Hard Word: {}", style(&round_data.word_data.hard_word).red());
+            if let Some(left) = game.time_left() {
+                print!(
+                    "  {}",
+                    // it doesn't count down while we wait for a guess
+                    style(format!(
+                        "{}:{:02} left when shown",
+                        left.as_secs() / 60,
+                        left.as_secs() % 60
+                    ))
+                    .cyan()
+                );
+            }
             println!();
+            println!();
+
+            for hint in hints.iter() {
+                println!("{}: {}", style("Hint").cyan(), hint);
+            }
+            if !hints.is_empty() {
+                println!();
+            }
+
+            if let Some(feedback) = feedback.take() {
+                println!("{}", feedback);
+                println!();
+            }
             print!("What was the {}?", style("original word").yellow());
 
             if first_time {
                 println!();
             } else {
-                println!(" ('exit' to quit)");
+                println!(" ('hint' for a hint, 'exit' to quit)");
             }
             println!();
 
@@ -46,22 +222,164 @@ fn main() {
             guess = dialoguer::Input::new().interact_text_on(&console).unwrap();
             guess = guess.trim().to_lowercase();
 
-            if guess == round_data.word_data.secret {
-                guess.clear();
-                break 'inner;
+            if guess.is_empty() {
+                continue;
             }
 
             if guess == "exit" {
                 break 'outer;
             }
 
-            if guess == "cheat" {
-                cheat = true;
+            if guess == "hint" {
+                if game.tick() {
+                    feedback = Some(out_of_time(&game));
+                    break 'inner;
+                }
+
+                match game.hint(hints.len()) {
+                    Some(hint) => hints.push(hint),
+                    None => feedback = Some("That's every hint there is!".to_string()),
+                }
+                first_time = false;
+                continue;
+            }
+
+            match game.submit_guess(&guess) {
+                GuessOutcome::Correct | GuessOutcome::AlternativeSolution => {
+                    guess.clear();
+                    break 'inner;
+                }
+                GuessOutcome::ValidButWrong(hard_word) => {
+                    feedback = Some(format!("{} would become {}", guess, style(hard_word).red()));
+                }
+                GuessOutcome::NotAWord => {
+                    feedback = Some(format!("{} isn't a word", guess));
+                }
+                GuessOutcome::TimeUp => {
+                    feedback = Some(out_of_time(&game));
+                    break 'inner;
+                }
+            }
+
+            if game.state() == GameState::Lost {
+                break 'outer;
             }
 
             first_time = false;
         }
     }
 
-    println!("Good job on winning, or giving up!");
+    if game.state() == GameState::InProgress {
+        autosave(&game);
+    } else {
+        clear_autosave();
+    }
+
+    if let Some(feedback) = feedback {
+        println!("{}", feedback);
+        println!();
+    }
+
+    if let (GameState::Lost, Some(round_data)) = (game.state(), game.round_data()) {
+        let secret = &round_data.word_data.secret;
+        println!("Out of lives! The word was {}", style(secret).yellow());
+        println!();
+    }
+
+    if let Some(share) = game.share_string() {
+        println!("{}", share);
+        println!();
+    }
+
+    if let GameMode::Endless(_) = game.mode() {
+        println!(
+            "You got {} rounds deep, and your best is {}",
+            game.current_round(),
+            game.best_depth()
+        );
+        println!();
+        save_best_depth(game.best_depth());
+    }
+
+    println!("{}", game.scorecard(&ScoreFormula::default()));
+}
+
+/// Tells the player what the word was in the round they just ran out of time on.
+fn out_of_time(game: &Game) -> String {
+    let failed = game.iter().nth(game.current_round() - 1).unwrap();
+
+    format!("Out of time! The word was {}", failed.word_data.secret)
+}
+
+/// Where a file the game keeps between runs goes.
+fn data_path(name: &str) -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+
+    Some(PathBuf::from(home).join(".words-are-hard").join(name))
+}
+
+/// Loads the game the player left halfway through, if there is one.
+fn load_autosave() -> Option<Game> {
+    let txt = std::fs::read_to_string(data_path("autosave.json")?).ok()?;
+
+    match Game::load(&txt) {
+        Ok(game) if game.state() == GameState::InProgress => Some(game),
+        Ok(_) => None,
+        Err(e) => {
+            eprintln!("couldn't resume your last game: {}", e);
+            None
+        }
+    }
+}
+
+/// Saves the game so it can be resumed if the player leaves halfway through.
+fn autosave(game: &Game) {
+    if let Some(path) = data_path("autosave.json") {
+        let saved = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, game.save()));
+
+        if let Err(e) = saved {
+            eprintln!("couldn't save your game to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn clear_autosave() {
+    if let Some(path) = data_path("autosave.json") {
+        // there's nothing to clear if the player never got to guess
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn load_best_depth() -> usize {
+    data_path("best-depth")
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|txt| txt.trim().parse().ok())
+        .unwrap_or_default()
+}
+
+fn save_best_depth(best_depth: usize) {
+    if let Some(path) = data_path("best-depth") {
+        let saved = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, best_depth.to_string()));
+
+        if let Err(e) = saved {
+            eprintln!("couldn't save your best depth to {}: {}", path.display(), e);
+        }
+    }
+}
+
+fn tutorial() -> Game {
+    Game::new_instructions(&[
+        ChallengeInstruction::new(Rule::Convert(TargetDestination::new('r', 'e')), "arbitrary"),
+        ChallengeInstruction::new(Rule::Convert(TargetDestination::new('i', 't')), "warranties"),
+        ChallengeInstruction::new(Rule::Convert(TargetDestination::new('n', 'o')), "signatures"),
+        ChallengeInstruction::new(Rule::Remove(Remove::new('h')), "horoscope"),
+        ChallengeInstruction::new(Rule::Duplicate(Duplicate::new('c', 2)), "helicopter"),
+        ChallengeInstruction::new(Rule::Switch(TargetDestination::new('c', 'e')), "convicted"),
+    ])
 }